@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use frames_core::{
+    serializers::HtmlSerializer,
+    types::{
+        errors::FrameErrors,
+        frame::{Frame, ValidationOptions},
+    },
+};
+
+#[derive(Parser)]
+#[command(name = "frames", version, about = "Validate, inspect and render Farcaster Frames")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Parse and validate the frame served at a URL or stored in an HTML file
+    Validate { source: String },
+    /// Print the parsed frame of a URL or HTML file
+    Inspect {
+        source: String,
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+    },
+    /// Render a JSON frame spec into frame HTML
+    Render {
+        /// Path to the JSON spec, or `-` to read it from stdin
+        spec: String,
+        /// Wrap the meta tags into a complete HTML document
+        #[arg(long)]
+        document: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Json,
+    Table,
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Validate { source } => {
+            let (frame, errors) = match load(&source) {
+                Ok(loaded) => loaded,
+                Err(e) => return fail(&e),
+            };
+            match errors {
+                None => {
+                    println!("{} is a valid frame ({} buttons)", source, frame.buttons.len());
+                    ExitCode::SUCCESS
+                }
+                Some(errors) => {
+                    eprint!("{}", errors);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Inspect { source, format } => {
+            let (mut frame, errors) = match load(&source) {
+                Ok(loaded) => loaded,
+                Err(e) => return fail(&e),
+            };
+            frame.buttons.sort_by_key(|button| button.id);
+            match format {
+                Format::Json => match serde_json::to_string_pretty(&frame) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => return fail(&e.to_string()),
+                },
+                Format::Table => print!("{}", table(&frame)),
+            }
+            if let Some(errors) = errors {
+                eprint!("{}", errors);
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Render { spec, document } => {
+            let json = match read_source(&spec) {
+                Ok(json) => json,
+                Err(e) => return fail(&e),
+            };
+            let frame: Frame = match serde_json::from_str(&json) {
+                Ok(frame) => frame,
+                Err(e) => return fail(&format!("Invalid frame spec: {}", e)),
+            };
+            if let Err(errors) = frame.validate_with(&ValidationOptions::local()) {
+                eprint!("{}", errors);
+                return ExitCode::FAILURE;
+            }
+            if document {
+                println!(
+                    "<!DOCTYPE html><html><head>{}</head><body></body></html>",
+                    frame.to_html()
+                );
+            } else {
+                println!("{}", frame.to_html());
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// Loads frames with local URLs accepted, as they are usually served by a local dev server.
+fn load(source: &str) -> Result<(Frame, Option<FrameErrors>), String> {
+    let mut frame = Frame::new();
+    let options = ValidationOptions::local();
+    let errors = if source.starts_with("http://") || source.starts_with("https://") {
+        frame.from_url_with(source, &options).err()
+    } else {
+        frame.from_html_with(&read_source(source)?, &options).err()
+    };
+    Ok((frame, errors))
+}
+
+fn read_source(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))
+}
+
+fn table(frame: &Frame) -> String {
+    let mut rows = vec![
+        ("title".to_string(), frame.title.clone()),
+        ("version".to_string(), frame.version.clone()),
+        ("image".to_string(), frame.image.url.clone()),
+        ("aspect_ratio".to_string(), frame.image.aspect_ratio.to_string()),
+        ("post_url".to_string(), frame.post_url.clone().unwrap_or("-".to_string())),
+        ("input_text".to_string(), frame.input_text.clone().unwrap_or("-".to_string())),
+    ];
+    for button in &frame.buttons {
+        let mut value =
+            format!("{} ({})", button.label, button.action.as_deref().unwrap_or("post"));
+        if let Some(target) = &button.target {
+            value += &format!(" -> {}", target);
+        }
+        rows.push((format!("button:{}", button.id), value));
+    }
+
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    rows.iter().map(|(key, value)| format!("{:width$}  {}\n", key, value, width = width)).collect()
+}
+
+fn fail(message: &str) -> ExitCode {
+    eprintln!("error: {}", message);
+    ExitCode::from(2)
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        process::{Command, Output},
+        thread,
+    };
+
+    fn frames(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_frames"))
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .unwrap()
+    }
+
+    fn serve_fixture(path: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            let html = std::fs::read_to_string(format!(
+                "{}/tests/fixtures/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                path
+            ))
+            .unwrap();
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    html.len(),
+                    html
+                );
+            }
+        });
+        format!("http://localhost:{}/", port)
+    }
+
+    #[test]
+    fn it_validates_frames_served_from_a_fixture_server() {
+        let url = serve_fixture("valid.html");
+        let output = frames(&["validate", &url]);
+
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("is a valid frame (2 buttons)"));
+    }
+
+    #[test]
+    fn it_exits_with_failure_and_error_keys_for_invalid_frames() {
+        let output = frames(&["validate", "tests/fixtures/invalid.html"]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stderr.contains("fc:frame:image"));
+        assert!(stderr.contains("fc:frame:button:1:action"));
+    }
+
+    #[test]
+    fn it_inspects_frames_as_json_and_table() {
+        let output = frames(&["inspect", "tests/fixtures/valid.html"]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["image"]["aspect_ratio"], "1:1");
+        assert_eq!(json["buttons"][1]["label"], "No");
+
+        let output = frames(&["inspect", "tests/fixtures/valid.html", "--format", "table"]);
+        let table = String::from_utf8_lossy(&output.stdout);
+        assert!(table.contains("post_url      http://localhost:3000/vote"));
+        assert!(table.contains("button:1      Yes (post)"));
+    }
+
+    #[test]
+    fn it_renders_json_specs_into_html() {
+        let output = frames(&["render", "tests/fixtures/frame.json"]);
+
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            r#"<title>Poll</title><meta name="fc:frame" content="vNext" /><meta name="fc:frame:image" content="https://example.com/poll.png" /><meta name="fc:frame:image:aspect_ratio" content="1.91:1" /><meta name="fc:frame:button:1" content="Yes" /><meta name="fc:frame:button:2" content="Results" /><meta name="fc:frame:button:2:action" content="link" /><meta name="fc:frame:button:2:target" content="https://example.com/results" /><meta name="fc:frame:post_url" content="https://example.com/vote" />"#
+        );
+    }
+}
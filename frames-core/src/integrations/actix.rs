@@ -0,0 +1,88 @@
+use std::{future::Future, pin::Pin};
+
+use actix_web::{
+    body::BoxBody,
+    dev::Payload,
+    http::{header::ContentType, StatusCode},
+    web::{Data, Json},
+    FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
+};
+
+use crate::{
+    serializers::HtmlSerializer,
+    types::{
+        action::{FrameAction, ValidatedFrameAction},
+        errors::{ErrorCode, FrameErrors},
+        external::FrameActionPayload,
+        frame::Frame,
+    },
+    validators::action::{action_error, FrameActionVerifier},
+};
+
+impl ResponseError for FrameErrors {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self)
+    }
+}
+
+/// Verifies Farcaster actions, checking their signer with the [`FrameActionVerifier`]
+/// registered as app data.
+impl FromRequest for ValidatedFrameAction {
+    type Error = FrameErrors;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let verifier = verifier(req);
+        let json = Json::<FrameActionPayload>::from_request(req, payload);
+        Box::pin(async move {
+            let payload = json.await.map_err(payload_error)?;
+            payload.verify(verifier?.signers()).await
+        })
+    }
+}
+
+/// Verifies actions of any protocol with the [`FrameActionVerifier`] registered as app data.
+impl FromRequest for FrameAction {
+    type Error = FrameErrors;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let verifier = verifier(req);
+        let json = Json::<serde_json::Value>::from_request(req, payload);
+        Box::pin(async move {
+            let payload = json.await.map_err(payload_error)?;
+            verifier?.verify(&payload).await
+        })
+    }
+}
+
+fn verifier(req: &HttpRequest) -> Result<FrameActionVerifier, FrameErrors> {
+    req.app_data::<FrameActionVerifier>()
+        .cloned()
+        .or_else(|| req.app_data::<Data<FrameActionVerifier>>().map(|data| data.as_ref().clone()))
+        .ok_or_else(|| {
+            action_error(
+                ErrorCode::FailedToCheckSigner,
+                "No FrameActionVerifier is registered as app data.",
+            )
+        })
+}
+
+fn payload_error(e: actix_web::Error) -> FrameErrors {
+    action_error(ErrorCode::InvalidActionPayload, &e.to_string())
+}
+
+impl Responder for Frame {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok().content_type(ContentType::html()).body(format!(
+            "<!DOCTYPE html><html><head>{}</head><body></body></html>",
+            self.to_html()
+        ))
+    }
+}
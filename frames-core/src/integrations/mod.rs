@@ -0,0 +1,2 @@
+#[cfg(feature = "actix")]
+pub mod actix;
@@ -7,9 +7,24 @@ use regex::Regex;
 lazy_static! {
     static ref URL_REGEX: Regex =
         Regex::new(r"^https?://(?:www\.)?[\w.-]+\.[a-zA-Z]{2,}(?:[/\?#][^\s]*)?$").unwrap();
+    /// [`URL_REGEX`] also accepting `localhost` and IPv4 hosts with a port, for frames served
+    /// locally while developing them.
+    static ref DEV_URL_REGEX: Regex = Regex::new(
+        r"^https?://(?:(?:www\.)?[\w.-]+\.[a-zA-Z]{2,}|localhost|\d{1,3}(?:\.\d{1,3}){3})(?::\d{1,5})?(?:[/\?#][^\s]*)?$"
+    )
+    .unwrap();
+    static ref IMAGE_DATA_URI_REGEX: Regex =
+        Regex::new(r"^data:image/(?:png|jpeg|gif|webp);base64,[A-Za-z0-9+/]+={0,2}$").unwrap();
 }
 
+pub mod image_url;
+pub mod integrations;
 pub mod provider;
+#[cfg(feature = "image")]
+pub mod render;
+pub mod router;
 pub mod serializers;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
 pub mod validators;
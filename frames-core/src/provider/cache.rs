@@ -0,0 +1,45 @@
+use std::{
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+
+const DEFAULT_CAPACITY: usize = 10_000;
+
+/// In-memory map whose entries expire `ttl` after being inserted. Holds at most 10,000 entries,
+/// evicting the least recently used ones first.
+#[derive(Debug)]
+pub(crate) struct TtlCache<K: Eq + Hash, V> {
+    ttl: Duration,
+    entries: Mutex<LruCache<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(DEFAULT_CAPACITY).expect("Invalid Cache Capacity");
+        TtlCache { ttl, entries: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().expect("Cache Lock Error");
+        match entries.get(key) {
+            Some((inserted, value)) if inserted.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, key: K, value: V) {
+        self.entries.lock().expect("Cache Lock Error").put(key, (Instant::now(), value));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().expect("Cache Lock Error").clear();
+    }
+}
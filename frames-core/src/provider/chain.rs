@@ -0,0 +1,102 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use ethers::providers::{Http, Middleware, Provider};
+
+use crate::{
+    provider::{farcaster::FarcasterProvider, gating::get_token_balances, hub::HubClient},
+    types::token::{TokenGateResult, TokenRequirement},
+};
+
+/// Chain the Farcaster contracts (IdRegistry, KeyRegistry, …) are deployed on: OP mainnet.
+pub const FARCASTER_CHAIN_ID: u64 = 10;
+
+/// Middleware per chain id. Farcaster lookups always go through [`FARCASTER_CHAIN_ID`] while
+/// token-gating, mint validation and transaction simulation can target any registered chain.
+#[derive(Debug)]
+pub struct ChainRegistry<M: Middleware + 'static> {
+    chains: HashMap<u64, Arc<M>>,
+}
+
+impl<M: Middleware + 'static> Default for ChainRegistry<M> {
+    fn default() -> Self {
+        ChainRegistry { chains: HashMap::new() }
+    }
+}
+
+impl<M: Middleware + 'static> Clone for ChainRegistry<M> {
+    fn clone(&self) -> Self {
+        ChainRegistry { chains: self.chains.clone() }
+    }
+}
+
+impl<M: Middleware + 'static> ChainRegistry<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_chain(mut self, chain_id: u64, middleware: M) -> Self {
+        self.chains.insert(chain_id, Arc::new(middleware));
+        self
+    }
+
+    pub fn get(&self, chain_id: u64) -> Option<Arc<M>> {
+        self.chains.get(&chain_id).cloned()
+    }
+
+    pub fn supports(&self, chain_id: u64) -> bool {
+        self.chains.contains_key(&chain_id)
+    }
+
+    pub fn chain_ids(&self) -> Vec<u64> {
+        let mut chain_ids: Vec<u64> = self.chains.keys().copied().collect();
+        chain_ids.sort_unstable();
+        chain_ids
+    }
+
+    /// Like [`ChainRegistry::get`], failing with [`UnsupportedChain`] for unregistered chains.
+    pub fn middleware(&self, chain_id: u64) -> Result<Arc<M>, UnsupportedChain> {
+        self.get(chain_id).ok_or(UnsupportedChain(chain_id))
+    }
+
+    /// Provider bound to OP mainnet, where the Farcaster contracts live.
+    pub fn farcaster(&self) -> Result<FarcasterProvider<M>, UnsupportedChain> {
+        self.middleware(FARCASTER_CHAIN_ID).map(FarcasterProvider::from_arc)
+    }
+
+    /// Like [`FarcasterProvider::check_token_gate`], but resolves the identity of `fid` on
+    /// OP mainnet and reads the balances on `chain_id`.
+    pub async fn check_token_gate(
+        &self,
+        chain_id: u64,
+        fid: u64,
+        hub: &HubClient,
+        requirement: &TokenRequirement,
+    ) -> Result<TokenGateResult, Box<dyn std::error::Error>> {
+        let addresses = self.farcaster()?.get_identity(fid, hub).await?.addresses();
+        let balances =
+            get_token_balances(self.middleware(chain_id)?, &addresses, requirement).await?;
+
+        Ok(TokenGateResult::new(fid, addresses.into_iter().zip(balances).collect(), requirement))
+    }
+}
+
+impl ChainRegistry<Provider<Http>> {
+    pub fn with_rpc_url(
+        self,
+        chain_id: u64,
+        rpc_url: &str,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(self.with_chain(chain_id, Provider::<Http>::try_from(rpc_url)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedChain(pub u64);
+
+impl fmt::Display for UnsupportedChain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no middleware registered for chain {}", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedChain {}
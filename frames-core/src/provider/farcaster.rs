@@ -1,12 +1,51 @@
 use std::sync::Arc;
 
-use ethers::providers::Middleware;
 use ethers::{
     abi::Abi,
     contract::Contract,
-    types::{Address, U256},
+    providers::Middleware,
+    types::{Address, Bytes, U256},
 };
 
+use crate::{provider::hub::HubClient, types::identity::FarcasterIdentity};
+
+pub const ID_REGISTRY_ADDRESS: &str = "0x00000000Fc6c5F01Fc30151999387Bb99A9f489b";
+
+const ID_REGISTRY_ABI: &str = r#"[{
+    "inputs":[{"internalType":"uint256","name":"fid","type":"uint256"}],
+    "name":"custodyOf",
+    "outputs":[{"internalType":"address","name":"","type":"address"}],
+    "stateMutability":"view","type":"function"
+},{
+    "inputs":[{"internalType":"address","name":"owner","type":"address"}],
+    "name":"idOf",
+    "outputs":[{"internalType":"uint256","name":"fid","type":"uint256"}],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+pub const KEY_REGISTRY_ADDRESS: &str = "0x00000000Fc1237824fb747aBDE0FF18990E59b7e";
+
+const KEY_REGISTRY_ABI: &str = r#"[{
+    "inputs":[
+        {"internalType":"uint256","name":"fid","type":"uint256"},
+        {"internalType":"bytes","name":"key","type":"bytes"}
+    ],
+    "name":"keyDataOf",
+    "outputs":[{
+        "components":[
+            {"internalType":"enum IKeyRegistry.KeyState","name":"state","type":"uint8"},
+            {"internalType":"uint32","name":"keyType","type":"uint32"}
+        ],
+        "internalType":"struct IKeyRegistry.KeyData","name":"","type":"tuple"
+    }],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+/// `KeyState.ADDED`, keys are `NULL` before being added and `REMOVED` after.
+const KEY_STATE_ADDED: u8 = 1;
+/// The only key type, Ed25519 keys signing Farcaster messages.
+const KEY_TYPE_ED25519: u32 = 1;
+
 pub struct FarcasterProvider<T: Middleware + 'static> {
     pub inner: Arc<T>,
 }
@@ -16,25 +55,74 @@ impl<T: Middleware + 'static> FarcasterProvider<T> {
         Self { inner: Arc::new(provider) }
     }
 
+    pub fn from_arc(inner: Arc<T>) -> Self {
+        Self { inner }
+    }
+
     pub async fn get_custody_address_by_fid(
         &self,
         fid: usize,
     ) -> Result<Option<Address>, Box<dyn std::error::Error>> {
-        let contract_address: Address =
-            "0x00000000Fc6c5F01Fc30151999387Bb99A9f489b".parse().expect("Parse Address Error");
-        let contract_abi: Abi = serde_json::from_str(
-            r#"[{
-            "inputs":[{"internalType":"uint256","name":"fid","type":"uint256"}],
-            "name":"custodyOf",
-            "outputs":[{"internalType":"address","name":"","type":"address"}],
-            "stateMutability":"view","type":"function"
-        }]"#,
-        )?;
-
-        let contract = Contract::new(contract_address, contract_abi, self.inner.clone());
+        let contract = self.id_registry()?;
         match contract.method::<_, Address>("custodyOf", U256::from(fid)).unwrap().call().await {
             Ok(value) => Ok(Some(value)),
             Err(e) => Err(Box::new(e)),
         }
     }
+
+    /// Returns the fid owned by `address`, or `None` when it does not custody any fid.
+    pub async fn fid_of(
+        &self,
+        address: Address,
+    ) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        let contract = self.id_registry()?;
+        match contract.method::<_, U256>("idOf", address).unwrap().call().await {
+            Ok(fid) if fid.is_zero() => Ok(None),
+            Ok(fid) => Ok(Some(fid.as_u64())),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Whether `key` is an Ed25519 key `fid` has added to the KeyRegistry and not removed since.
+    pub async fn is_active_signer(
+        &self,
+        fid: u64,
+        key: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let contract = self.key_registry()?;
+        let args = (U256::from(fid), Bytes::from(key.to_vec()));
+        match contract.method::<_, (u8, u32)>("keyDataOf", args).unwrap().call().await {
+            Ok((state, key_type)) => Ok(state == KEY_STATE_ADDED && key_type == KEY_TYPE_ED25519),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Merges the on-chain custody address of `fid` with the verified addresses reported by `hub`.
+    pub async fn get_identity(
+        &self,
+        fid: u64,
+        hub: &HubClient,
+    ) -> Result<FarcasterIdentity, Box<dyn std::error::Error>> {
+        let custody_address = self
+            .get_custody_address_by_fid(fid as usize)
+            .await?
+            .filter(|address| !address.is_zero());
+        let verified_addresses = hub.get_verified_addresses(fid).await?;
+
+        Ok(FarcasterIdentity { fid, custody_address, verified_addresses })
+    }
+
+    fn id_registry(&self) -> Result<Contract<T>, Box<dyn std::error::Error>> {
+        let contract_address: Address = ID_REGISTRY_ADDRESS.parse().expect("Parse Address Error");
+        let contract_abi: Abi = serde_json::from_str(ID_REGISTRY_ABI)?;
+
+        Ok(Contract::new(contract_address, contract_abi, self.inner.clone()))
+    }
+
+    fn key_registry(&self) -> Result<Contract<T>, Box<dyn std::error::Error>> {
+        let contract_address: Address = KEY_REGISTRY_ADDRESS.parse().expect("Parse Address Error");
+        let contract_abi: Abi = serde_json::from_str(KEY_REGISTRY_ABI)?;
+
+        Ok(Contract::new(contract_address, contract_abi, self.inner.clone()))
+    }
 }
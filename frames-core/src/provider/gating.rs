@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use ethers::{
+    abi::{Abi, Token},
+    contract::{Contract, Multicall, MULTICALL_ADDRESS},
+    providers::Middleware,
+    types::{Address, U256},
+};
+
+use crate::{
+    provider::{farcaster::FarcasterProvider, hub::HubClient},
+    types::token::{TokenGateResult, TokenRequirement, TokenStandard},
+};
+
+const BALANCE_OF_ABI: &str = r#"[{
+    "inputs":[{"internalType":"address","name":"owner","type":"address"}],
+    "name":"balanceOf",
+    "outputs":[{"internalType":"uint256","name":"","type":"uint256"}],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+const ERC1155_BALANCE_OF_ABI: &str = r#"[{
+    "inputs":[
+        {"internalType":"address","name":"account","type":"address"},
+        {"internalType":"uint256","name":"id","type":"uint256"}
+    ],
+    "name":"balanceOf",
+    "outputs":[{"internalType":"uint256","name":"","type":"uint256"}],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+/// Balances of `addresses` for the token of `requirement` on the chain of `middleware`,
+/// fetched in a single Multicall3 round trip. Calls that revert count as a zero balance.
+pub async fn get_token_balances<M: Middleware + 'static>(
+    middleware: Arc<M>,
+    addresses: &[Address],
+    requirement: &TokenRequirement,
+) -> Result<Vec<U256>, Box<dyn std::error::Error>> {
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let abi: Abi = match requirement.standard {
+        TokenStandard::Erc1155 { .. } => serde_json::from_str(ERC1155_BALANCE_OF_ABI)?,
+        _ => serde_json::from_str(BALANCE_OF_ABI)?,
+    };
+    let contract = Contract::new(requirement.contract, abi, middleware.clone());
+
+    let mut multicall = Multicall::new(middleware, Some(MULTICALL_ADDRESS)).await?;
+    for address in addresses {
+        let call = match requirement.standard {
+            TokenStandard::Erc1155 { token_id } => {
+                contract.method::<_, U256>("balanceOf", (*address, token_id))?
+            }
+            _ => contract.method::<_, U256>("balanceOf", *address)?,
+        };
+        multicall.add_call(call, true);
+    }
+
+    let balances = multicall
+        .call_raw()
+        .await?
+        .into_iter()
+        .map(|result| match result {
+            Ok(Token::Uint(balance)) => balance,
+            _ => U256::zero(),
+        })
+        .collect();
+
+    Ok(balances)
+}
+
+impl<T: Middleware + 'static> FarcasterProvider<T> {
+    /// [`get_token_balances`] on OP mainnet.
+    pub async fn get_token_balances(
+        &self,
+        addresses: &[Address],
+        requirement: &TokenRequirement,
+    ) -> Result<Vec<U256>, Box<dyn std::error::Error>> {
+        get_token_balances(self.inner.clone(), addresses, requirement).await
+    }
+
+    /// Resolves the addresses of a verified `fid` and checks that together they hold at least
+    /// `requirement.min_balance` of the token.
+    pub async fn check_token_gate(
+        &self,
+        fid: u64,
+        hub: &HubClient,
+        requirement: &TokenRequirement,
+    ) -> Result<TokenGateResult, Box<dyn std::error::Error>> {
+        let addresses = self.get_identity(fid, hub).await?.addresses();
+        let balances = self.get_token_balances(&addresses, requirement).await?;
+
+        Ok(TokenGateResult::new(fid, addresses.into_iter().zip(balances).collect(), requirement))
+    }
+}
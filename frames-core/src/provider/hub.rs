@@ -0,0 +1,167 @@
+use ethers::types::Address;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::types::{
+    hub::{HubError, HubMessage, HubMessagesResponse, OnChainEvent},
+    profile::UserProfile,
+};
+
+pub const DEFAULT_HUB_URL: &str = "https://nemes.farcaster.xyz:2281";
+
+/// Client for the HTTP API exposed by Farcaster hubs.
+pub struct HubClient {
+    pub base_url: String,
+    client: reqwest::Client,
+}
+
+impl HubClient {
+    pub fn new(base_url: &str) -> Self {
+        HubClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Ethereum addresses verified by `fid`, in the order the hub returns them.
+    pub async fn get_verified_addresses(
+        &self,
+        fid: u64,
+    ) -> Result<Vec<Address>, Box<dyn std::error::Error>> {
+        let messages = self.get_messages("verificationsByFid", &[("fid", fid.to_string())]).await?;
+
+        let mut addresses = Vec::new();
+        for message in messages {
+            let body = match message.data.verification_add_address_body {
+                Some(body) => body,
+                None => continue,
+            };
+            if body.protocol.as_deref().is_some_and(|protocol| protocol != "PROTOCOL_ETHEREUM") {
+                continue;
+            }
+            if let Ok(address) = body.address.parse::<Address>() {
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+            }
+        }
+
+        Ok(addresses)
+    }
+
+    /// Profile of `fid` built from its latest `UserData` messages and verified addresses.
+    pub async fn get_user_profile(
+        &self,
+        fid: u64,
+    ) -> Result<UserProfile, Box<dyn std::error::Error>> {
+        let mut messages = self.get_messages("userDataByFid", &[("fid", fid.to_string())]).await?;
+        // later messages of the same type replace earlier ones
+        messages.sort_by_key(|message| message.data.timestamp);
+
+        let mut profile = UserProfile { fid, ..Default::default() };
+        for body in messages.into_iter().filter_map(|message| message.data.user_data_body) {
+            let field = match body.data_type.as_str() {
+                "USER_DATA_TYPE_USERNAME" => &mut profile.username,
+                "USER_DATA_TYPE_DISPLAY" => &mut profile.display_name,
+                "USER_DATA_TYPE_PFP" => &mut profile.pfp_url,
+                "USER_DATA_TYPE_BIO" => &mut profile.bio,
+                "USER_DATA_TYPE_URL" => &mut profile.url,
+                _ => continue,
+            };
+            *field = Some(body.value).filter(|value| !value.is_empty());
+        }
+        profile.verified_addresses = self.get_verified_addresses(fid).await?;
+
+        Ok(profile)
+    }
+
+    /// Fetches a single message from `/v1/{endpoint}`, or `None` when the hub does not have it.
+    pub async fn get_message(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Result<Option<HubMessage>, Box<dyn std::error::Error>> {
+        self.get_one(endpoint, query).await
+    }
+
+    /// Whether `signer` is an Ed25519 key of `fid`, from the KeyRegistry events the hub indexed.
+    /// Hubs only return the add event of keys which have not been removed since.
+    pub async fn is_active_signer(
+        &self,
+        fid: u64,
+        signer: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let signer = format!("0x{}", hex::encode(signer));
+        let event: Option<OnChainEvent> = self
+            .get_one("onChainSignersByFid", &[("fid", fid.to_string()), ("signer", signer.clone())])
+            .await?;
+
+        Ok(event
+            .filter(|event| event.fid == fid)
+            .and_then(|event| event.signer_event_body)
+            .is_some_and(|body| {
+                body.event_type == "SIGNER_EVENT_TYPE_ADD" && body.key.eq_ignore_ascii_case(&signer)
+            }))
+    }
+
+    async fn get_one<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Result<Option<T>, Box<dyn std::error::Error>> {
+        let response = self
+            .client
+            .get(format!("{}/v1/{}", self.base_url, endpoint))
+            .query(query)
+            .send()
+            .await?;
+
+        // hubs answer missing messages with a 400 carrying `"errCode": "not_found"`
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::BAD_REQUEST => {
+                let error: HubError = response.json().await?;
+                match error.err_code.as_str() {
+                    "not_found" => Ok(None),
+                    _ => Err(Box::new(error)),
+                }
+            }
+            _ => Ok(Some(response.error_for_status()?.json().await?)),
+        }
+    }
+
+    /// Fetches every page of a `/v1/{endpoint}` messages response.
+    pub async fn get_messages(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Result<Vec<HubMessage>, Box<dyn std::error::Error>> {
+        let mut messages = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut request =
+                self.client.get(format!("{}/v1/{}", self.base_url, endpoint)).query(query);
+            if let Some(token) = &page_token {
+                request = request.query(&[("pageToken", token)]);
+            }
+
+            let response: HubMessagesResponse =
+                request.send().await?.error_for_status()?.json().await?;
+            messages.extend(response.messages);
+
+            match response.next_page_token.filter(|token| !token.is_empty()) {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+impl Default for HubClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_HUB_URL)
+    }
+}
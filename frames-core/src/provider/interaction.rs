@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+
+use crate::{provider::hub::HubClient, types::action::ActionCastId};
+
+const REACTION_TYPE_LIKE: &str = "1";
+const REACTION_TYPE_RECAST: &str = "2";
+
+/// Checks for "like/recast to unlock" and "follow to see" frames. `cast_id` is usually the
+/// `cast_id` of the verified frame action.
+#[async_trait]
+pub trait InteractionProvider: Send + Sync {
+    async fn has_liked(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+
+    async fn has_recasted(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+
+    /// Whether `fid` follows `target_fid`.
+    async fn follows(&self, fid: u64, target_fid: u64) -> Result<bool, Box<dyn std::error::Error>>;
+}
+
+impl HubClient {
+    async fn has_reaction(
+        &self,
+        fid: u64,
+        reaction_type: &str,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = [
+            ("fid", fid.to_string()),
+            ("reaction_type", reaction_type.to_string()),
+            ("target_fid", cast_id.fid.to_string()),
+            ("target_hash", cast_id.hash.clone()),
+        ];
+        Ok(self.get_message("reactionById", &query).await?.is_some())
+    }
+}
+
+#[async_trait]
+impl InteractionProvider for HubClient {
+    async fn has_liked(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        self.has_reaction(fid, REACTION_TYPE_LIKE, cast_id).await
+    }
+
+    async fn has_recasted(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        self.has_reaction(fid, REACTION_TYPE_RECAST, cast_id).await
+    }
+
+    async fn follows(&self, fid: u64, target_fid: u64) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = [
+            ("fid", fid.to_string()),
+            ("target_fid", target_fid.to_string()),
+            ("link_type", "follow".to_string()),
+        ];
+        Ok(self.get_message("linkById", &query).await?.is_some())
+    }
+}
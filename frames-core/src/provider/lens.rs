@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ethers::{
+    abi::Abi,
+    contract::Contract,
+    providers::Middleware,
+    types::{Address, U256},
+};
+
+pub const LENS_HUB_ADDRESS: &str = "0xDb46d1Dc155634FbC732f92E853b10B288AD5a1d";
+
+const LENS_HUB_ABI: &str = r#"[{
+    "inputs":[{"internalType":"uint256","name":"tokenId","type":"uint256"}],
+    "name":"ownerOf",
+    "outputs":[{"internalType":"address","name":"","type":"address"}],
+    "stateMutability":"view","type":"function"
+},{
+    "inputs":[
+        {"internalType":"uint256","name":"delegatorProfileId","type":"uint256"},
+        {"internalType":"address","name":"delegatedExecutor","type":"address"}
+    ],
+    "name":"isDelegatedExecutorApproved",
+    "outputs":[{"internalType":"bool","name":"","type":"bool"}],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+/// Source of the addresses allowed to act for Lens profiles, implemented by [`LensHubProvider`]
+/// and the `testing` feature's `LensTestSigner`. A Lens action is signed by a wallet; it is the
+/// profile's action once that wallet owns the profile or is one of its delegated executors.
+#[async_trait]
+pub trait LensProfileProvider: Send + Sync {
+    async fn is_profile_signer(
+        &self,
+        profile_id: U256,
+        signer: Address,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+}
+
+/// Reads profile ownership and delegated executors from the LensHub contract on Polygon.
+pub struct LensHubProvider<T: Middleware + 'static> {
+    pub inner: Arc<T>,
+}
+
+impl<T: Middleware + 'static> LensHubProvider<T> {
+    pub fn new(provider: T) -> Self {
+        Self { inner: Arc::new(provider) }
+    }
+
+    pub fn from_arc(inner: Arc<T>) -> Self {
+        Self { inner }
+    }
+
+    pub async fn owner_of(&self, profile_id: U256) -> Result<Address, Box<dyn std::error::Error>> {
+        let contract = self.lens_hub()?;
+        match contract.method::<_, Address>("ownerOf", profile_id).unwrap().call().await {
+            Ok(owner) => Ok(owner),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    pub async fn is_delegated_executor(
+        &self,
+        profile_id: U256,
+        executor: Address,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let contract = self.lens_hub()?;
+        let args = (profile_id, executor);
+        match contract.method::<_, bool>("isDelegatedExecutorApproved", args).unwrap().call().await
+        {
+            Ok(approved) => Ok(approved),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    /// Whether `signer` owns `profile_id` or is approved as one of its delegated executors.
+    pub async fn is_profile_signer(
+        &self,
+        profile_id: U256,
+        signer: Address,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        if self.owner_of(profile_id).await? == signer {
+            return Ok(true);
+        }
+        self.is_delegated_executor(profile_id, signer).await
+    }
+
+    fn lens_hub(&self) -> Result<Contract<T>, Box<dyn std::error::Error>> {
+        let contract_address: Address = LENS_HUB_ADDRESS.parse().expect("Parse Address Error");
+        let contract_abi: Abi = serde_json::from_str(LENS_HUB_ABI)?;
+
+        Ok(Contract::new(contract_address, contract_abi, self.inner.clone()))
+    }
+}
+
+#[async_trait]
+impl<T: Middleware + 'static> LensProfileProvider for LensHubProvider<T> {
+    async fn is_profile_signer(
+        &self,
+        profile_id: U256,
+        signer: Address,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        LensHubProvider::is_profile_signer(self, profile_id, signer).await
+    }
+}
@@ -1 +1,12 @@
+mod cache;
+pub mod chain;
 pub mod farcaster;
+pub mod gating;
+pub mod hub;
+pub mod interaction;
+#[cfg(feature = "lens")]
+pub mod lens;
+pub mod profile;
+pub mod signer;
+pub mod simulation;
+pub mod username;
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::{
+    provider::{cache::TtlCache, hub::HubClient},
+    types::profile::UserProfile,
+};
+
+/// Source of [`UserProfile`]s, implemented by [`HubClient`], [`CachedProfiles`] and the
+/// `testing` feature's fixtures.
+#[async_trait]
+pub trait ProfileProvider: Send + Sync {
+    async fn get_user_profile(&self, fid: u64) -> Result<UserProfile, Box<dyn std::error::Error>>;
+}
+
+#[async_trait]
+impl ProfileProvider for HubClient {
+    async fn get_user_profile(&self, fid: u64) -> Result<UserProfile, Box<dyn std::error::Error>> {
+        HubClient::get_user_profile(self, fid).await
+    }
+}
+
+/// Wraps a [`ProfileProvider`] and keeps the profiles it returns for `ttl`.
+pub struct CachedProfiles<P: ProfileProvider> {
+    inner: P,
+    profiles: TtlCache<u64, UserProfile>,
+}
+
+impl<P: ProfileProvider> CachedProfiles<P> {
+    pub fn new(inner: P, ttl: Duration) -> Self {
+        CachedProfiles { inner, profiles: TtlCache::new(ttl) }
+    }
+
+    pub fn clear_cache(&self) {
+        self.profiles.clear();
+    }
+}
+
+#[async_trait]
+impl<P: ProfileProvider> ProfileProvider for CachedProfiles<P> {
+    async fn get_user_profile(&self, fid: u64) -> Result<UserProfile, Box<dyn std::error::Error>> {
+        if let Some(profile) = self.profiles.get(&fid) {
+            return Ok(profile);
+        }
+
+        let profile = self.inner.get_user_profile(fid).await?;
+        self.profiles.insert(fid, profile.clone());
+        Ok(profile)
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use ethers::providers::Middleware;
+
+use crate::provider::{farcaster::FarcasterProvider, hub::HubClient};
+
+/// Source of the Ed25519 keys fids sign their messages with, implemented by [`HubClient`],
+/// [`FarcasterProvider`] and the `testing` feature's `TestSigner`. A valid signature only proves
+/// the message was signed by its `signer`; it is the fid's message once the signer is one of the
+/// fid's keys.
+#[async_trait]
+pub trait SignerProvider: Send + Sync {
+    /// Whether `signer` is a key `fid` has added and not removed since.
+    async fn is_active_signer(
+        &self,
+        fid: u64,
+        signer: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+}
+
+#[async_trait]
+impl SignerProvider for HubClient {
+    async fn is_active_signer(
+        &self,
+        fid: u64,
+        signer: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        HubClient::is_active_signer(self, fid, signer).await
+    }
+}
+
+#[async_trait]
+impl<T: Middleware + 'static> SignerProvider for FarcasterProvider<T> {
+    async fn is_active_signer(
+        &self,
+        fid: u64,
+        signer: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        FarcasterProvider::is_active_signer(self, fid, signer).await
+    }
+}
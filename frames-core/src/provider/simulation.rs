@@ -0,0 +1,105 @@
+use ethers::{
+    abi::{self, ParamType, Token},
+    providers::{Middleware, MiddlewareError},
+    types::{transaction::eip2718::TypedTransaction, Address, Bytes, U256},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    provider::{chain::ChainRegistry, farcaster::FarcasterProvider},
+    types::transaction::FrameTransaction,
+};
+
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SimulationResult {
+    Success { gas: U256, return_data: Bytes },
+    Reverted { reason: RevertReason },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RevertReason {
+    /// `revert("…")` or a failed `require`.
+    Error(String),
+    /// Solidity panic code, e.g. `0x11` for an arithmetic overflow.
+    Panic(U256),
+    /// Custom error or undecodable revert data.
+    Custom(Bytes),
+}
+
+impl RevertReason {
+    pub fn decode(data: &Bytes) -> Self {
+        let decoded = match data.get(..4) {
+            Some(selector) if selector == ERROR_SELECTOR => {
+                abi::decode(&[ParamType::String], &data[4..]).ok()
+            }
+            Some(selector) if selector == PANIC_SELECTOR => {
+                abi::decode(&[ParamType::Uint(256)], &data[4..]).ok()
+            }
+            _ => None,
+        };
+
+        match decoded.as_deref() {
+            Some([Token::String(message)]) => RevertReason::Error(message.clone()),
+            Some([Token::Uint(code)]) => RevertReason::Panic(*code),
+            _ => RevertReason::Custom(data.clone()),
+        }
+    }
+}
+
+/// Simulates sending `transaction` from `from` with `eth_call` and `eth_estimateGas` through
+/// `middleware`. Reverts are returned as [`SimulationResult::Reverted`], other failures as errors.
+pub async fn simulate_transaction<M: Middleware + 'static>(
+    middleware: &M,
+    transaction: &FrameTransaction,
+    from: Address,
+) -> Result<SimulationResult, Box<dyn std::error::Error>> {
+    let request: TypedTransaction = transaction.request(from)?.into();
+
+    let return_data = match middleware.call(&request, None).await {
+        Ok(return_data) => return_data,
+        Err(e) => return revert_or_error(e),
+    };
+    let gas = match middleware.estimate_gas(&request, None).await {
+        Ok(gas) => gas,
+        Err(e) => return revert_or_error(e),
+    };
+
+    Ok(SimulationResult::Success { gas, return_data })
+}
+
+impl<T: Middleware + 'static> FarcasterProvider<T> {
+    /// [`simulate_transaction`] on OP mainnet.
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &FrameTransaction,
+        from: Address,
+    ) -> Result<SimulationResult, Box<dyn std::error::Error>> {
+        simulate_transaction(self.inner.as_ref(), transaction, from).await
+    }
+}
+
+impl<M: Middleware + 'static> ChainRegistry<M> {
+    /// Simulates `transaction` on the chain named by its `chainId`.
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &FrameTransaction,
+        from: Address,
+    ) -> Result<SimulationResult, Box<dyn std::error::Error>> {
+        let middleware = self.middleware(transaction.chain_id()?)?;
+        simulate_transaction(middleware.as_ref(), transaction, from).await
+    }
+}
+
+fn revert_or_error<E: MiddlewareError + 'static>(
+    error: E,
+) -> Result<SimulationResult, Box<dyn std::error::Error>> {
+    match error.as_error_response().and_then(|response| response.as_revert_data()) {
+        Some(data) => Ok(SimulationResult::Reverted { reason: RevertReason::decode(&data) }),
+        None => Err(Box::new(error)),
+    }
+}
@@ -0,0 +1,156 @@
+use std::{any::Any, sync::Arc, time::Duration};
+
+use ethers::providers::{Middleware, MiddlewareError, ProviderError};
+use reqwest::StatusCode;
+
+use crate::{
+    provider::{
+        cache::TtlCache,
+        chain::{ChainRegistry, UnsupportedChain, FARCASTER_CHAIN_ID},
+    },
+    types::fname::{FnameTransfer, FnameTransferResponse},
+};
+
+pub const DEFAULT_FNAME_SERVER_URL: &str = "https://fnames.farcaster.xyz";
+
+/// Chain ENS names are resolved on.
+pub const ENS_CHAIN_ID: u64 = 1;
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Resolves usernames to fids and back. Fnames go through the fname registry server, `.eth`
+/// names through ENS on mainnet and the IdRegistry on OP mainnet, both taken from `chains`.
+/// Results, including names that do not resolve, are cached for an hour by default.
+pub struct UsernameResolver<M: Middleware + 'static> {
+    pub fname_server_url: String,
+    client: reqwest::Client,
+    chains: ChainRegistry<M>,
+    fids: TtlCache<String, Option<u64>>,
+    usernames: TtlCache<u64, Option<String>>,
+}
+
+impl<M: Middleware + 'static> UsernameResolver<M> {
+    pub fn new(fname_server_url: &str, chains: ChainRegistry<M>) -> Self {
+        UsernameResolver {
+            fname_server_url: fname_server_url.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            chains,
+            fids: TtlCache::new(DEFAULT_CACHE_TTL),
+            usernames: TtlCache::new(DEFAULT_CACHE_TTL),
+        }
+    }
+
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.fids = TtlCache::new(ttl);
+        self.usernames = TtlCache::new(ttl);
+        self
+    }
+
+    pub fn clear_cache(&self) {
+        self.fids.clear();
+        self.usernames.clear();
+    }
+
+    /// Fid owning `username` (an fname or an ENS name, with or without a leading `@`). ENS names
+    /// do not resolve when ENS is not configured.
+    pub async fn fid_of(&self, username: &str) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        let username = username.trim_start_matches('@').to_lowercase();
+        if let Some(fid) = self.fids.get(&username) {
+            return Ok(fid);
+        }
+
+        let fid = if username.ends_with(".eth") {
+            if !self.chains.supports(ENS_CHAIN_ID) || !self.chains.supports(FARCASTER_CHAIN_ID) {
+                return Ok(None);
+            }
+            self.ens_fid_of(&username).await?
+        } else {
+            self.current_transfer(&[("name", username.clone())]).await?.map(|transfer| transfer.to)
+        };
+
+        self.fids.insert(username, fid);
+        Ok(fid)
+    }
+
+    /// Fname of `fid`, falling back to the ENS name of its custody address when ENS is
+    /// configured.
+    pub async fn username_of(
+        &self,
+        fid: u64,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        if let Some(username) = self.usernames.get(&fid) {
+            return Ok(username);
+        }
+
+        let mut username = self
+            .current_transfer(&[("fid", fid.to_string())])
+            .await?
+            .map(|transfer| transfer.username);
+        if username.is_none() &&
+            self.chains.supports(ENS_CHAIN_ID) &&
+            self.chains.supports(FARCASTER_CHAIN_ID)
+        {
+            username = self.ens_username_of(fid).await?;
+        }
+
+        self.usernames.insert(fid, username.clone());
+        Ok(username)
+    }
+
+    async fn current_transfer(
+        &self,
+        query: &[(&str, String)],
+    ) -> Result<Option<FnameTransfer>, Box<dyn std::error::Error>> {
+        let response = self
+            .client
+            .get(format!("{}/transfers/current", self.fname_server_url))
+            .query(query)
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response: FnameTransferResponse = response.error_for_status()?.json().await?;
+        // a transfer to fid 0 unregisters the name
+        Ok(Some(response.transfer).filter(|transfer| transfer.to != 0))
+    }
+
+    async fn ens_fid_of(&self, name: &str) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        let address = match self.ens()?.resolve_name(name).await {
+            Ok(address) => address,
+            Err(e) if is_unresolved(&e) => return Ok(None),
+            Err(e) => return Err(Box::new(e)),
+        };
+
+        self.chains.farcaster()?.fid_of(address).await
+    }
+
+    async fn ens_username_of(
+        &self,
+        fid: u64,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let custody_address =
+            self.chains.farcaster()?.get_custody_address_by_fid(fid as usize).await?;
+        let custody_address = match custody_address.filter(|address| !address.is_zero()) {
+            Some(address) => address,
+            None => return Ok(None),
+        };
+
+        match self.ens()?.lookup_address(custody_address).await {
+            Ok(name) => Ok(Some(name)),
+            Err(e) if is_unresolved(&e) => Ok(None),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    fn ens(&self) -> Result<Arc<M>, UnsupportedChain> {
+        self.chains.middleware(ENS_CHAIN_ID)
+    }
+}
+
+fn is_unresolved<E: MiddlewareError + 'static>(error: &E) -> bool {
+    // a `ProviderError` does not report itself through `as_provider_error`
+    let error = error.as_provider_error().or_else(|| (error as &dyn Any).downcast_ref());
+    matches!(error, Some(ProviderError::EnsError(_)) | Some(ProviderError::EnsNotOwned(_)))
+}
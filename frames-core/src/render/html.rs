@@ -0,0 +1,767 @@
+//! Lays out a restricted HTML/CSS subset into SVG, in the spirit of Satori, and rasterizes it.
+//!
+//! Every element is a flex container styled through its `style` attribute. Supported
+//! properties: `display` (`flex`, `none`), `position`, `top`/`right`/`bottom`/`left`,
+//! `flex-direction`, `flex-wrap`, `flex`, `flex-grow`, `flex-shrink`, `flex-basis`,
+//! `justify-content`, `align-items`, `align-self`, `gap`, `row-gap`, `column-gap`, `width`,
+//! `height`, `min-*`/`max-*`, `margin*`, `padding*`, `border`, `border-width`, `border-color`,
+//! `border-radius`, `background`, `background-color`, `opacity`, `color`, `font-family`,
+//! `font-size`, `font-weight`, `text-align` and `object-fit`. Other properties are ignored.
+//! Text is converted to paths with the registered [`Fonts`], and `<img>` sources must be
+//! data URIs.
+
+use std::fmt::Write;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use resvg::usvg;
+use scraper::{ElementRef, Html, Node};
+use taffy::{
+    prelude::*, AlignSelf, AvailableSpace, LengthPercentage, LengthPercentageAuto, Position,
+};
+use tiny_skia::{PathSegment, Pixmap, Transform};
+
+use crate::{
+    render::{
+        encode_png, png_data_uri, render_error, size,
+        text::{line_height, text_path, text_width, wrap, TextAlign, TextLayout},
+        Color, Fonts, DEFAULT_FONT,
+    },
+    types::{
+        errors::FrameErrors,
+        image::{AspectRatio, FrameImage},
+    },
+};
+
+/// HTML rendered into a frame image of `aspect_ratio`, `width` pixels wide.
+///
+/// ```
+/// use frames_core::render::{Fonts, HtmlImage};
+/// use frames_core::types::image::AspectRatio;
+///
+/// let image = HtmlImage::new(
+///     r#"<div style="width: 100%; height: 100%; background: #111; color: white;
+///                    align-items: center; justify-content: center; font-size: 64px">
+///          42 votes
+///        </div>"#,
+///     AspectRatio::OneToOne,
+/// );
+/// assert!(image.to_svg(&Fonts::default()).unwrap().starts_with("<svg"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlImage {
+    pub html: String,
+    pub aspect_ratio: AspectRatio,
+    pub width: u32,
+}
+
+impl HtmlImage {
+    pub fn new(html: impl Into<String>, aspect_ratio: AspectRatio) -> Self {
+        HtmlImage { html: html.into(), aspect_ratio, width: 1200 }
+    }
+
+    /// HTML sized to the aspect ratio of `image`.
+    pub fn for_image(html: impl Into<String>, image: &FrameImage) -> Self {
+        Self::new(html, image.aspect_ratio.clone())
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn to_svg(&self, fonts: &Fonts) -> Result<String, FrameErrors> {
+        let (width, height) = size(self.width, &self.aspect_ratio)?;
+        let document = Html::parse_fragment(&self.html);
+
+        let mut builder = Builder { tree: TaffyTree::new(), fonts };
+        let root = builder.root(document.root_element(), width as f32, height as f32)?;
+        builder
+            .tree
+            .compute_layout_with_measure(
+                root,
+                Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+                |known, available, _, context, _| measure(known, available, context, fonts),
+            )
+            .map_err(layout_error)?;
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        );
+        builder.emit(root, (0.0, 0.0), None, &mut svg)?;
+        svg.push_str("</svg>");
+        Ok(svg)
+    }
+
+    pub fn render(&self, fonts: &Fonts) -> Result<Pixmap, FrameErrors> {
+        let svg = self.to_svg(fonts)?;
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default())
+            .map_err(|e| render_error(format!("Failed to parse the generated SVG: {}", e)))?;
+
+        let size = tree.size().to_int_size();
+        let mut pixmap = Pixmap::new(size.width(), size.height()).ok_or_else(|| {
+            render_error(format!("Cannot render a {}x{} image.", size.width(), size.height()))
+        })?;
+        pixmap.fill(tiny_skia::Color::WHITE);
+        resvg::render(&tree, Transform::identity(), &mut pixmap.as_mut());
+        Ok(pixmap)
+    }
+
+    pub fn render_png(&self, fonts: &Fonts) -> Result<Vec<u8>, FrameErrors> {
+        encode_png(&self.render(fonts)?)
+    }
+
+    pub fn to_data_uri(&self, fonts: &Fonts) -> Result<String, FrameErrors> {
+        Ok(png_data_uri(&self.render_png(fonts)?))
+    }
+
+    pub fn to_frame_image(&self, fonts: &Fonts) -> Result<FrameImage, FrameErrors> {
+        Ok(FrameImage { url: self.to_data_uri(fonts)?, aspect_ratio: self.aspect_ratio.clone() })
+    }
+}
+
+enum NodeKind {
+    Element(Visual),
+    Text(TextNode),
+    Image(ImageNode),
+}
+
+struct Visual {
+    background: Option<Color>,
+    border_color: Color,
+    border_radius: f32,
+    opacity: f32,
+}
+
+struct TextNode {
+    text: String,
+    style: TextStyle,
+}
+
+struct ImageNode {
+    href: String,
+    fit: &'static str,
+    intrinsic: Option<(f32, f32)>,
+}
+
+/// Inherited text properties.
+#[derive(Clone)]
+struct TextStyle {
+    color: Color,
+    font_size: f32,
+    bold: bool,
+    font_family: String,
+    align: TextAlign,
+}
+
+impl TextStyle {
+    fn font<'a>(&self, fonts: &'a Fonts) -> Result<ttf_parser::Face<'a>, FrameErrors> {
+        let bold = format!("{} Bold", self.font_family);
+        if self.bold && fonts.contains(&bold) {
+            return fonts.get(&bold);
+        }
+        fonts.get(&self.font_family)
+    }
+}
+
+struct Builder<'a> {
+    tree: TaffyTree<NodeKind>,
+    fonts: &'a Fonts,
+}
+
+impl Builder<'_> {
+    /// Root container of the image size holding the top level nodes of the fragment.
+    fn root(&mut self, html: ElementRef, width: f32, height: f32) -> Result<NodeId, FrameErrors> {
+        let text = TextStyle {
+            color: Color::BLACK,
+            font_size: 16.0,
+            bold: false,
+            font_family: DEFAULT_FONT.to_string(),
+            align: TextAlign::Left,
+        };
+        let style = Style {
+            display: Display::Flex,
+            size: Size { width: length(width), height: length(height) },
+            ..Default::default()
+        };
+        let visual = Visual {
+            background: None,
+            border_color: Color::BLACK,
+            border_radius: 0.0,
+            opacity: 1.0,
+        };
+
+        let children = self.children(html, &text)?;
+        self.tree
+            .new_with_children(style, &children)
+            .and_then(|root| {
+                self.tree.set_node_context(root, Some(NodeKind::Element(visual))).map(|_| root)
+            })
+            .map_err(layout_error)
+    }
+
+    fn children(
+        &mut self,
+        element: ElementRef,
+        text: &TextStyle,
+    ) -> Result<Vec<NodeId>, FrameErrors> {
+        let mut children = Vec::new();
+        for child in element.children() {
+            let node = match child.value() {
+                Node::Element(_) => match ElementRef::wrap(child) {
+                    Some(element) => self.element(element, text)?,
+                    None => None,
+                },
+                Node::Text(content) => self.text(content, text)?,
+                _ => None,
+            };
+            children.extend(node);
+        }
+        Ok(children)
+    }
+
+    fn text(&mut self, content: &str, style: &TextStyle) -> Result<Option<NodeId>, FrameErrors> {
+        let text = content.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            return Ok(None);
+        }
+        // fail early on unknown fonts rather than while measuring
+        style.font(self.fonts)?;
+
+        let node = NodeKind::Text(TextNode { text, style: style.clone() });
+        self.tree.new_leaf_with_context(Style::default(), node).map(Some).map_err(layout_error)
+    }
+
+    fn element(
+        &mut self,
+        element: ElementRef,
+        inherited: &TextStyle,
+    ) -> Result<Option<NodeId>, FrameErrors> {
+        let name = element.value().name();
+        let mut style = Style { display: Display::Flex, ..Default::default() };
+        let mut visual = Visual {
+            background: None,
+            border_color: inherited.color,
+            border_radius: 0.0,
+            opacity: 1.0,
+        };
+        let mut text = inherited.clone();
+        let mut fit = "none";
+
+        match name {
+            "h1" => (text.font_size, text.bold) = (inherited.font_size * 2.0, true),
+            "h2" => (text.font_size, text.bold) = (inherited.font_size * 1.5, true),
+            "h3" => (text.font_size, text.bold) = (inherited.font_size * 1.17, true),
+            "b" | "strong" => text.bold = true,
+            "script" | "style" | "head" | "title" => return Ok(None),
+            _ => {}
+        }
+        if name == "img" {
+            style.size = Size {
+                width: attribute_length(element, "width"),
+                height: attribute_length(element, "height"),
+            };
+        }
+
+        for (property, value) in declarations(element.value().attr("style").unwrap_or_default()) {
+            if property == "display" && value == "none" {
+                return Ok(None);
+            }
+            if property == "object-fit" {
+                fit = match value.as_str() {
+                    "cover" => "xMidYMid slice",
+                    "contain" | "scale-down" => "xMidYMid meet",
+                    _ => "none",
+                };
+                continue;
+            }
+            apply(&property, &value, &mut style, &mut visual, &mut text, inherited.font_size)
+                .map_err(|description| {
+                    render_error(format!("<{}> {}: {}: {}", name, property, value, description))
+                })?;
+        }
+
+        if name == "img" {
+            let src = element.value().attr("src").unwrap_or_default();
+            if !src.starts_with("data:image/") {
+                return Err(render_error(format!(
+                    "<img> sources must be data URIs, got {}.",
+                    src.chars().take(64).collect::<String>()
+                )));
+            }
+            let node =
+                NodeKind::Image(ImageNode { href: src.to_string(), fit, intrinsic: png_size(src) });
+            return self.tree.new_leaf_with_context(style, node).map(Some).map_err(layout_error);
+        }
+
+        let children = self.children(element, &text)?;
+        let node = self.tree.new_with_children(style, &children).map_err(layout_error)?;
+        self.tree.set_node_context(node, Some(NodeKind::Element(visual))).map_err(layout_error)?;
+        Ok(Some(node))
+    }
+
+    /// Writes `node` at `offset`. `line` is the horizontal extent a lone text child aligns in.
+    fn emit(
+        &self,
+        node: NodeId,
+        offset: (f32, f32),
+        line: Option<(f32, f32)>,
+        svg: &mut String,
+    ) -> Result<(), FrameErrors> {
+        let layout = self.tree.layout(node).map_err(layout_error)?;
+        let (x, y) = (offset.0 + layout.location.x, offset.1 + layout.location.y);
+        let (width, height) = (layout.size.width, layout.size.height);
+
+        match self.tree.get_node_context(node) {
+            Some(NodeKind::Element(visual)) => {
+                if visual.opacity < 1.0 {
+                    let _ = write!(svg, r#"<g opacity="{}">"#, visual.opacity);
+                }
+                if let Some(background) = visual.background {
+                    let _ = write!(
+                        svg,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" {}/>"#,
+                        x,
+                        y,
+                        width,
+                        height,
+                        visual.border_radius,
+                        paint("fill", background)
+                    );
+                }
+                let border = layout.border.top;
+                if border > 0.0 {
+                    let _ = write!(
+                        svg,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" fill="none" stroke-width="{}" {}/>"#,
+                        x + border / 2.0,
+                        y + border / 2.0,
+                        width - border,
+                        height - border,
+                        (visual.border_radius - border / 2.0).max(0.0),
+                        border,
+                        paint("stroke", visual.border_color)
+                    );
+                }
+                let children = self.tree.children(node).map_err(layout_error)?;
+                let content = (
+                    x + layout.padding.left + layout.border.left,
+                    width -
+                        layout.padding.left -
+                        layout.padding.right -
+                        layout.border.left -
+                        layout.border.right,
+                );
+                let line = if children.len() == 1 { Some(content) } else { None };
+                for child in children {
+                    self.emit(child, (x, y), line, svg)?;
+                }
+                if visual.opacity < 1.0 {
+                    svg.push_str("</g>");
+                }
+            }
+            Some(NodeKind::Text(text)) => {
+                let face = text.style.font(self.fonts)?;
+                let (left, extent) = line.unwrap_or((x, width));
+                let anchor = match text.style.align {
+                    TextAlign::Left => left,
+                    TextAlign::Center => left + extent / 2.0,
+                    TextAlign::Right => left + extent,
+                };
+                let layout = TextLayout {
+                    x: anchor,
+                    y,
+                    size: text.style.font_size,
+                    align: text.style.align,
+                    // tolerate rounding so the text wraps as it was measured
+                    max_width: Some(width + 0.01),
+                };
+                if let Some(path) = text_path(&face, &text.text, &layout) {
+                    let _ = write!(
+                        svg,
+                        r#"<path d="{}" {}/>"#,
+                        path_data(&path),
+                        paint("fill", text.style.color)
+                    );
+                }
+            }
+            Some(NodeKind::Image(image)) => {
+                let _ = write!(
+                    svg,
+                    r#"<image x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="{}" href="{}"/>"#,
+                    x,
+                    y,
+                    width,
+                    height,
+                    image.fit,
+                    escape(&image.href)
+                );
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn measure(
+    known: Size<Option<f32>>,
+    available: Size<AvailableSpace>,
+    context: Option<&mut NodeKind>,
+    fonts: &Fonts,
+) -> Size<f32> {
+    if let Size { width: Some(width), height: Some(height) } = known {
+        return Size { width, height };
+    }
+
+    match context {
+        Some(NodeKind::Text(text)) => {
+            let face = match text.style.font(fonts) {
+                Ok(face) => face,
+                Err(_) => return Size::ZERO,
+            };
+            let size = text.style.font_size;
+            let max_width = match (known.width, available.width) {
+                (Some(width), _) => Some(width),
+                (None, AvailableSpace::Definite(width)) => Some(width),
+                // the widest word
+                (None, AvailableSpace::MinContent) => Some(0.0),
+                (None, AvailableSpace::MaxContent) => None,
+            };
+
+            let lines = wrap(&face, &text.text, size, max_width);
+            let width = lines.iter().map(|line| text_width(&face, line, size)).fold(0.0, f32::max);
+            Size {
+                width: known.width.unwrap_or(width),
+                height: known.height.unwrap_or(lines.len() as f32 * line_height(size)),
+            }
+        }
+        Some(NodeKind::Image(image)) => {
+            let (intrinsic_width, intrinsic_height) = image.intrinsic.unwrap_or((0.0, 0.0));
+            match (known.width, known.height) {
+                (Some(width), None) if intrinsic_width > 0.0 => {
+                    Size { width, height: width * intrinsic_height / intrinsic_width }
+                }
+                (None, Some(height)) if intrinsic_height > 0.0 => {
+                    Size { width: height * intrinsic_width / intrinsic_height, height }
+                }
+                _ => Size {
+                    width: known.width.unwrap_or(intrinsic_width),
+                    height: known.height.unwrap_or(intrinsic_height),
+                },
+            }
+        }
+        _ => Size::ZERO,
+    }
+}
+
+/// `property: value` pairs of a `style` attribute, with lowercase property names.
+fn declarations(style: &str) -> Vec<(String, String)> {
+    style
+        .split(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .map(|(property, value)| (property.trim().to_lowercase(), value.trim().to_string()))
+        .filter(|(property, value)| !property.is_empty() && !value.is_empty())
+        .collect()
+}
+
+fn apply(
+    property: &str,
+    value: &str,
+    style: &mut Style,
+    visual: &mut Visual,
+    text: &mut TextStyle,
+    parent_font_size: f32,
+) -> Result<(), String> {
+    let em = text.font_size;
+    match property {
+        "display" if value == "flex" => {}
+        "position" => {
+            style.position = match value {
+                "relative" | "static" => Position::Relative,
+                "absolute" => Position::Absolute,
+                _ => return Err(unsupported()),
+            }
+        }
+        "top" => style.inset.top = length_auto(value, em)?,
+        "right" => style.inset.right = length_auto(value, em)?,
+        "bottom" => style.inset.bottom = length_auto(value, em)?,
+        "left" => style.inset.left = length_auto(value, em)?,
+        "flex-direction" => {
+            style.flex_direction = match value {
+                "row" => FlexDirection::Row,
+                "column" => FlexDirection::Column,
+                "row-reverse" => FlexDirection::RowReverse,
+                "column-reverse" => FlexDirection::ColumnReverse,
+                _ => return Err(unsupported()),
+            }
+        }
+        "flex-wrap" => {
+            style.flex_wrap = match value {
+                "nowrap" => FlexWrap::NoWrap,
+                "wrap" => FlexWrap::Wrap,
+                "wrap-reverse" => FlexWrap::WrapReverse,
+                _ => return Err(unsupported()),
+            }
+        }
+        "flex" => {
+            let mut parts = value.split_whitespace();
+            style.flex_grow = number(parts.next().unwrap_or_default())?;
+            style.flex_shrink = parts.next().map(number).transpose()?.unwrap_or(1.0);
+            style.flex_basis =
+                parts.next().map(|basis| dimension(basis, em)).transpose()?.unwrap_or(length(0.0));
+        }
+        "flex-grow" => style.flex_grow = number(value)?,
+        "flex-shrink" => style.flex_shrink = number(value)?,
+        "flex-basis" => style.flex_basis = dimension(value, em)?,
+        "justify-content" => style.justify_content = Some(content_alignment(value)?),
+        "align-items" => style.align_items = Some(item_alignment(value)?),
+        "align-self" => style.align_self = Some(item_alignment(value)?),
+        "gap" => {
+            let gap = length_percentage(value, em)?;
+            style.gap = Size { width: gap, height: gap };
+        }
+        "row-gap" => style.gap.height = length_percentage(value, em)?,
+        "column-gap" => style.gap.width = length_percentage(value, em)?,
+        "width" => style.size.width = dimension(value, em)?,
+        "height" => style.size.height = dimension(value, em)?,
+        "min-width" => style.min_size.width = dimension(value, em)?,
+        "min-height" => style.min_size.height = dimension(value, em)?,
+        "max-width" => style.max_size.width = dimension(value, em)?,
+        "max-height" => style.max_size.height = dimension(value, em)?,
+        "margin" => style.margin = sides(value, |value| length_auto(value, em))?,
+        "margin-top" => style.margin.top = length_auto(value, em)?,
+        "margin-right" => style.margin.right = length_auto(value, em)?,
+        "margin-bottom" => style.margin.bottom = length_auto(value, em)?,
+        "margin-left" => style.margin.left = length_auto(value, em)?,
+        "padding" => style.padding = sides(value, |value| length_percentage(value, em))?,
+        "padding-top" => style.padding.top = length_percentage(value, em)?,
+        "padding-right" => style.padding.right = length_percentage(value, em)?,
+        "padding-bottom" => style.padding.bottom = length_percentage(value, em)?,
+        "padding-left" => style.padding.left = length_percentage(value, em)?,
+        "border" => {
+            // `<width> [solid] [<color>]`, in any order
+            for part in value.split_whitespace() {
+                match part {
+                    "solid" => {}
+                    "none" => style.border = Rect::zero(),
+                    _ => match pixels(part, em) {
+                        Ok(width) => style.border = uniform(LengthPercentage::Length(width)),
+                        Err(_) => visual.border_color = color(part)?,
+                    },
+                }
+            }
+        }
+        "border-width" => style.border = uniform(LengthPercentage::Length(pixels(value, em)?)),
+        "border-color" => visual.border_color = color(value)?,
+        "border-radius" => visual.border_radius = pixels(value, em)?,
+        "background" | "background-color" => visual.background = Some(color(value)?),
+        "opacity" => visual.opacity = number(value)?.clamp(0.0, 1.0),
+        "color" => text.color = color(value)?,
+        "font-family" => {
+            let family = value.split(',').next().unwrap_or_default();
+            text.font_family = family.trim().trim_matches(|c| c == '"' || c == '\'').to_string();
+        }
+        "font-size" => {
+            text.font_size = match value.strip_suffix('%') {
+                Some(percent) => number(percent)? / 100.0 * parent_font_size,
+                None => pixels(value, parent_font_size)?,
+            }
+        }
+        "font-weight" => {
+            text.bold = match value {
+                "bold" | "bolder" => true,
+                "normal" | "lighter" => false,
+                _ => number(value)? >= 600.0,
+            }
+        }
+        "text-align" => {
+            text.align = match value {
+                "left" | "start" => TextAlign::Left,
+                "center" => TextAlign::Center,
+                "right" | "end" => TextAlign::Right,
+                _ => return Err(unsupported()),
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn unsupported() -> String {
+    "unsupported value".to_string()
+}
+
+fn number(value: &str) -> Result<f32, String> {
+    value.trim().parse().map_err(|_| "expected a number".to_string())
+}
+
+/// `12px`, `12`, or `1.5em` relative to `em`.
+fn pixels(value: &str, em: f32) -> Result<f32, String> {
+    if let Some(ems) = value.strip_suffix("em") {
+        return Ok(number(ems)? * em);
+    }
+    number(value.strip_suffix("px").unwrap_or(value)).map_err(|_| "expected a length".to_string())
+}
+
+fn length_percentage(value: &str, em: f32) -> Result<LengthPercentage, String> {
+    match value.strip_suffix('%') {
+        Some(percent) => Ok(LengthPercentage::Percent(number(percent)? / 100.0)),
+        None => Ok(LengthPercentage::Length(pixels(value, em)?)),
+    }
+}
+
+fn length_auto(value: &str, em: f32) -> Result<LengthPercentageAuto, String> {
+    match value {
+        "auto" => Ok(LengthPercentageAuto::Auto),
+        _ => Ok(length_percentage(value, em)?.into()),
+    }
+}
+
+fn dimension(value: &str, em: f32) -> Result<Dimension, String> {
+    match value {
+        "auto" => Ok(Dimension::Auto),
+        _ => Ok(length_percentage(value, em)?.into()),
+    }
+}
+
+fn uniform<T: Copy>(value: T) -> Rect<T> {
+    Rect { top: value, right: value, bottom: value, left: value }
+}
+
+/// CSS shorthand of one to four values for top, right, bottom and left.
+fn sides<T: Copy>(
+    value: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Rect<T>, String> {
+    let values = value.split_whitespace().map(parse).collect::<Result<Vec<T>, String>>()?;
+    match values.as_slice() {
+        [all] => Ok(uniform(*all)),
+        [vertical, horizontal] => {
+            Ok(Rect { top: *vertical, right: *horizontal, bottom: *vertical, left: *horizontal })
+        }
+        [top, horizontal, bottom] => {
+            Ok(Rect { top: *top, right: *horizontal, bottom: *bottom, left: *horizontal })
+        }
+        [top, right, bottom, left] => {
+            Ok(Rect { top: *top, right: *right, bottom: *bottom, left: *left })
+        }
+        _ => Err("expected one to four values".to_string()),
+    }
+}
+
+fn content_alignment(value: &str) -> Result<JustifyContent, String> {
+    match value {
+        "flex-start" | "start" => Ok(JustifyContent::FlexStart),
+        "flex-end" | "end" => Ok(JustifyContent::FlexEnd),
+        "center" => Ok(JustifyContent::Center),
+        "stretch" => Ok(JustifyContent::Stretch),
+        "space-between" => Ok(JustifyContent::SpaceBetween),
+        "space-around" => Ok(JustifyContent::SpaceAround),
+        "space-evenly" => Ok(JustifyContent::SpaceEvenly),
+        _ => Err(unsupported()),
+    }
+}
+
+fn item_alignment(value: &str) -> Result<AlignSelf, String> {
+    match value {
+        "flex-start" | "start" => Ok(AlignItems::FlexStart),
+        "flex-end" | "end" => Ok(AlignItems::FlexEnd),
+        "center" => Ok(AlignItems::Center),
+        "stretch" => Ok(AlignItems::Stretch),
+        "baseline" => Ok(AlignItems::Baseline),
+        _ => Err(unsupported()),
+    }
+}
+
+/// `#rgb`, `#rrggbb`, `#rrggbbaa`, `rgb(…)`, `rgba(…)`, `transparent` and a few named colors.
+fn color(value: &str) -> Result<Color, String> {
+    let value = value.trim().to_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() == 3 {
+            return hex.chars().flat_map(|c| [c, c]).collect::<String>().parse();
+        }
+        return hex.parse();
+    }
+    if let Some(arguments) = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|v| v.strip_suffix(')'))
+    {
+        let channels = arguments.split(',').map(number).collect::<Result<Vec<f32>, String>>()?;
+        return match channels.as_slice() {
+            [r, g, b] => Ok(Color::rgb(*r as u8, *g as u8, *b as u8)),
+            [r, g, b, a] => {
+                Ok(Color::rgba(*r as u8, *g as u8, *b as u8, (a * 255.0).round() as u8))
+            }
+            _ => Err("expected three or four channels".to_string()),
+        };
+    }
+
+    match value.as_str() {
+        "transparent" => Ok(Color::rgba(0, 0, 0, 0)),
+        "white" => Ok(Color::WHITE),
+        "black" => Ok(Color::BLACK),
+        "red" => Ok(Color::rgb(255, 0, 0)),
+        "green" => Ok(Color::rgb(0, 128, 0)),
+        "blue" => Ok(Color::rgb(0, 0, 255)),
+        "gray" | "grey" => Ok(Color::rgb(128, 128, 128)),
+        "purple" => Ok(Color::rgb(128, 0, 128)),
+        _ => Err("unsupported color".to_string()),
+    }
+}
+
+fn attribute_length(element: ElementRef, name: &str) -> Dimension {
+    element
+        .value()
+        .attr(name)
+        .and_then(|value| pixels(value, 16.0).ok())
+        .map(Dimension::Length)
+        .unwrap_or(Dimension::Auto)
+}
+
+/// Size of a base64 PNG data URI, read from its IHDR chunk.
+fn png_size(src: &str) -> Option<(f32, f32)> {
+    let data = src.strip_prefix("data:image/png;base64,")?;
+    // the IHDR width and height end at byte 24, within the first 32 base64 characters
+    let header = STANDARD.decode(data.get(..32)?).ok()?;
+    let width = u32::from_be_bytes(header.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(header.get(20..24)?.try_into().ok()?);
+    Some((width as f32, height as f32))
+}
+
+fn paint(attribute: &str, color: Color) -> String {
+    let rgb = Color { a: 255, ..color };
+    match color.a {
+        255 => format!(r#"{}="{}""#, attribute, rgb),
+        alpha => format!(r#"{0}="{1}" {0}-opacity="{2:.3}""#, attribute, rgb, alpha as f32 / 255.0),
+    }
+}
+
+fn path_data(path: &tiny_skia::Path) -> String {
+    let mut data = String::new();
+    for segment in path.segments() {
+        let _ = match segment {
+            PathSegment::MoveTo(p) => write!(data, "M{:.2} {:.2}", p.x, p.y),
+            PathSegment::LineTo(p) => write!(data, "L{:.2} {:.2}", p.x, p.y),
+            PathSegment::QuadTo(p1, p) => {
+                write!(data, "Q{:.2} {:.2} {:.2} {:.2}", p1.x, p1.y, p.x, p.y)
+            }
+            PathSegment::CubicTo(p1, p2, p) => write!(
+                data,
+                "C{:.2} {:.2} {:.2} {:.2} {:.2} {:.2}",
+                p1.x, p1.y, p2.x, p2.y, p.x, p.y
+            ),
+            PathSegment::Close => write!(data, "Z"),
+        };
+    }
+    data
+}
+
+fn escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn layout_error(e: taffy::TaffyError) -> FrameErrors {
+    render_error(format!("Failed to lay out the HTML: {}", e))
+}
@@ -0,0 +1,389 @@
+//! Server-side rendering of frame images into PNGs, enabled by the `image` feature.
+
+#[cfg(feature = "html")]
+mod html;
+mod text;
+
+use std::{collections::HashMap, fmt, str::FromStr, sync::Arc};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, PixmapPaint, Rect, Transform};
+
+use crate::types::{
+    errors::{Error, ErrorCode, FrameErrors},
+    image::{AspectRatio, FrameImage},
+};
+
+#[cfg(feature = "html")]
+pub use html::HtmlImage;
+pub use text::TextAlign;
+
+/// Font used by text elements that do not name one.
+pub const DEFAULT_FONT: &str = "DejaVu Sans";
+
+const DEJAVU_SANS: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const DEJAVU_SANS_BOLD: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+/// TrueType/OpenType fonts by name. [`Fonts::default`] bundles "DejaVu Sans" and
+/// "DejaVu Sans Bold" so rendering works without any system fonts.
+#[derive(Debug, Clone)]
+pub struct Fonts {
+    fonts: HashMap<String, Arc<Vec<u8>>>,
+}
+
+impl Fonts {
+    pub fn empty() -> Self {
+        Fonts { fonts: HashMap::new() }
+    }
+
+    pub fn with_font(mut self, name: &str, data: Vec<u8>) -> Result<Self, FrameErrors> {
+        if ttf_parser::Face::parse(&data, 0).is_err() {
+            return Err(render_error(format!("{} is not a valid TrueType or OpenType font.", name)));
+        }
+        self.fonts.insert(name.to_string(), Arc::new(data));
+        Ok(self)
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.fonts.contains_key(name)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Result<ttf_parser::Face<'_>, FrameErrors> {
+        self.fonts
+            .get(name)
+            .and_then(|data| ttf_parser::Face::parse(data, 0).ok())
+            .ok_or_else(|| render_error(format!("Font {} is not registered.", name)))
+    }
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        let mut fonts = Fonts::empty();
+        fonts.fonts.insert(DEFAULT_FONT.to_string(), Arc::new(DEJAVU_SANS.to_vec()));
+        fonts.fonts.insert("DejaVu Sans Bold".to_string(), Arc::new(DEJAVU_SANS_BOLD.to_vec()));
+        fonts
+    }
+}
+
+/// RGBA color, (de)serialized as `#rrggbb` or `#rrggbbaa`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    fn paint(&self) -> Paint<'static> {
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(self.r, self.g, self.b, self.a);
+        paint.anti_alias = true;
+        paint
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let hex = value.strip_prefix('#').unwrap_or(value);
+        let bytes = match hex.len() {
+            6 | 8 => hex::decode(hex).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("{} is not a #rrggbb or #rrggbbaa color.", value))?;
+
+        Ok(Color { r: bytes[0], g: bytes[1], b: bytes[2], a: bytes.get(3).copied().unwrap_or(255) })
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)?;
+        if self.a != 255 {
+            write!(f, "{:02x}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Drawing primitive of an [`ImageLayout`]. Coordinates are in pixels from the top left corner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", rename_all_fields = "camelCase")]
+pub enum Element {
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: Color,
+        #[serde(default)]
+        radius: f32,
+    },
+    /// Text whose first line box starts at `y`, aligned on `x`. Wraps on words when `max_width`
+    /// is set.
+    Text {
+        x: f32,
+        y: f32,
+        text: String,
+        size: f32,
+        color: Color,
+        #[serde(default)]
+        font: Option<String>,
+        #[serde(default)]
+        align: TextAlign,
+        #[serde(default)]
+        max_width: Option<f32>,
+    },
+    /// PNG image scaled into the given box, (de)serialized as base64.
+    Image {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        #[serde(with = "base64_bytes")]
+        png: Vec<u8>,
+    },
+}
+
+mod base64_bytes {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        STANDARD.decode(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Layout of a frame image, rendered into a PNG of its aspect ratio.
+///
+/// ```
+/// use frames_core::render::{Color, Element, Fonts, ImageLayout, TextAlign};
+/// use frames_core::types::image::AspectRatio;
+///
+/// let image = ImageLayout::new(AspectRatio::OneToOne)
+///     .background(Color::rgb(17, 17, 17))
+///     .element(Element::Text {
+///         x: 600.0,
+///         y: 560.0,
+///         text: "42 votes".to_string(),
+///         size: 64.0,
+///         color: Color::WHITE,
+///         font: None,
+///         align: TextAlign::Center,
+///         max_width: None,
+///     })
+///     .to_frame_image(&Fonts::default())
+///     .unwrap();
+/// assert!(image.url.starts_with("data:image/png;base64,"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageLayout {
+    #[serde(default)]
+    pub aspect_ratio: AspectRatio,
+    #[serde(default = "default_width")]
+    pub width: u32,
+    #[serde(default = "default_background")]
+    pub background: Color,
+    #[serde(default)]
+    pub elements: Vec<Element>,
+}
+
+fn default_width() -> u32 {
+    1200
+}
+
+fn default_background() -> Color {
+    Color::WHITE
+}
+
+impl ImageLayout {
+    pub fn new(aspect_ratio: AspectRatio) -> Self {
+        ImageLayout {
+            aspect_ratio,
+            width: default_width(),
+            background: default_background(),
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn element(mut self, element: Element) -> Self {
+        self.elements.push(element);
+        self
+    }
+
+    /// Size in pixels. Frames without an aspect ratio are displayed at 1.91:1.
+    pub fn size(&self) -> Result<(u32, u32), FrameErrors> {
+        size(self.width, &self.aspect_ratio)
+    }
+
+    pub fn render(&self, fonts: &Fonts) -> Result<Pixmap, FrameErrors> {
+        let (width, height) = self.size()?;
+        let mut pixmap = Pixmap::new(width, height)
+            .ok_or_else(|| render_error(format!("Cannot render a {}x{} image.", width, height)))?;
+        let background = self.background;
+        pixmap.fill(tiny_skia::Color::from_rgba8(
+            background.r,
+            background.g,
+            background.b,
+            background.a,
+        ));
+
+        for element in &self.elements {
+            draw(&mut pixmap, element, fonts)?;
+        }
+
+        Ok(pixmap)
+    }
+
+    pub fn render_png(&self, fonts: &Fonts) -> Result<Vec<u8>, FrameErrors> {
+        encode_png(&self.render(fonts)?)
+    }
+
+    pub fn to_data_uri(&self, fonts: &Fonts) -> Result<String, FrameErrors> {
+        Ok(png_data_uri(&self.render_png(fonts)?))
+    }
+
+    /// Renders the layout into a [`FrameImage`] carrying the PNG as a data URI.
+    pub fn to_frame_image(&self, fonts: &Fonts) -> Result<FrameImage, FrameErrors> {
+        Ok(FrameImage { url: self.to_data_uri(fonts)?, aspect_ratio: self.aspect_ratio.clone() })
+    }
+}
+
+pub(crate) fn size(width: u32, aspect_ratio: &AspectRatio) -> Result<(u32, u32), FrameErrors> {
+    match aspect_ratio {
+        AspectRatio::OneToOne => Ok((width, width)),
+        AspectRatio::None | AspectRatio::OnePointNineToOne => {
+            Ok((width, (width as f32 / 1.91).round() as u32))
+        }
+        AspectRatio::Error => Err(render_error("Images are either 1.91:1 or 1:1.".to_string())),
+    }
+}
+
+pub(crate) fn encode_png(pixmap: &Pixmap) -> Result<Vec<u8>, FrameErrors> {
+    pixmap.encode_png().map_err(|e| render_error(format!("Failed to encode the PNG: {}", e)))
+}
+
+pub fn png_data_uri(png: &[u8]) -> String {
+    format!("data:image/png;base64,{}", STANDARD.encode(png))
+}
+
+fn draw(pixmap: &mut Pixmap, element: &Element, fonts: &Fonts) -> Result<(), FrameErrors> {
+    match element {
+        Element::Rect { x, y, width, height, color, radius } => {
+            let path = match rounded_rect(*x, *y, *width, *height, *radius) {
+                Some(path) => path,
+                None => return Ok(()),
+            };
+            pixmap.fill_path(&path, &color.paint(), FillRule::Winding, Transform::identity(), None);
+        }
+        Element::Text { x, y, text, size, color, font, align, max_width } => {
+            let face = fonts.get(font.as_deref().unwrap_or(DEFAULT_FONT))?;
+            let layout = text::TextLayout {
+                x: *x,
+                y: *y,
+                size: *size,
+                align: *align,
+                max_width: *max_width,
+            };
+            if let Some(path) = text::text_path(&face, text, &layout) {
+                pixmap.fill_path(
+                    &path,
+                    &color.paint(),
+                    FillRule::Winding,
+                    Transform::identity(),
+                    None,
+                );
+            }
+        }
+        Element::Image { x, y, width, height, png } => {
+            let image = Pixmap::decode_png(png)
+                .map_err(|e| render_error(format!("Failed to decode the PNG image: {}", e)))?;
+            let transform = Transform::from_row(
+                width / image.width() as f32,
+                0.0,
+                0.0,
+                height / image.height() as f32,
+                *x,
+                *y,
+            );
+            let paint =
+                PixmapPaint { quality: tiny_skia::FilterQuality::Bicubic, ..Default::default() };
+            pixmap.draw_pixmap(0, 0, image.as_ref(), &paint, transform, None);
+        }
+    }
+
+    Ok(())
+}
+
+fn rounded_rect(x: f32, y: f32, width: f32, height: f32, radius: f32) -> Option<tiny_skia::Path> {
+    let rect = Rect::from_xywh(x, y, width, height)?;
+    let radius = radius.clamp(0.0, width.min(height) / 2.0);
+    if radius == 0.0 {
+        return Some(PathBuilder::from_rect(rect));
+    }
+
+    let (right, bottom) = (x + width, y + height);
+    let mut builder = PathBuilder::new();
+    builder.move_to(x + radius, y);
+    builder.line_to(right - radius, y);
+    builder.quad_to(right, y, right, y + radius);
+    builder.line_to(right, bottom - radius);
+    builder.quad_to(right, bottom, right - radius, bottom);
+    builder.line_to(x + radius, bottom);
+    builder.quad_to(x, bottom, x, bottom - radius);
+    builder.line_to(x, y + radius);
+    builder.quad_to(x, y, x + radius, y);
+    builder.close();
+    builder.finish()
+}
+
+pub(crate) fn render_error(description: String) -> FrameErrors {
+    let mut errors = FrameErrors::new();
+    errors.add_error(Error {
+        description,
+        code: ErrorCode::FailedToRenderImage,
+        key: Some("fc:frame:image".to_string()),
+    });
+    errors
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use tiny_skia::{Path, PathBuilder};
+use ttf_parser::{Face, OutlineBuilder};
+
+/// Line height as a multiple of the font size.
+const LINE_HEIGHT: f32 = 1.2;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+pub(crate) struct TextLayout {
+    pub x: f32,
+    pub y: f32,
+    pub size: f32,
+    pub align: TextAlign,
+    pub max_width: Option<f32>,
+}
+
+/// Width in pixels of `text` set in `face` at `size`.
+pub(crate) fn text_width(face: &Face, text: &str, size: f32) -> f32 {
+    let scale = size / face.units_per_em() as f32;
+    text.chars()
+        .filter_map(|c| face.glyph_index(c))
+        .map(|glyph| face.glyph_hor_advance(glyph).unwrap_or(0) as f32 * scale)
+        .sum()
+}
+
+/// Greedy word wrap of `text` into lines no wider than `max_width`. Explicit newlines are kept.
+pub(crate) fn wrap(face: &Face, text: &str, size: f32, max_width: Option<f32>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let max_width = match max_width {
+            Some(max_width) => max_width,
+            None => {
+                lines.push(paragraph.to_string());
+                continue;
+            }
+        };
+
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate =
+                if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if !line.is_empty() && text_width(face, &candidate, size) > max_width {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+pub(crate) fn line_height(size: f32) -> f32 {
+    size * LINE_HEIGHT
+}
+
+/// Outline of the glyphs of `text`, or `None` when nothing is visible.
+pub(crate) fn text_path(face: &Face, text: &str, layout: &TextLayout) -> Option<Path> {
+    let scale = layout.size / face.units_per_em() as f32;
+    let ascender = face.ascender() as f32 * scale;
+    // center the glyphs vertically in the line box
+    let leading =
+        (line_height(layout.size) - (face.ascender() - face.descender()) as f32 * scale) / 2.0;
+
+    let mut builder = GlyphPathBuilder { builder: PathBuilder::new(), scale, x: 0.0, y: 0.0 };
+    for (index, line) in wrap(face, text, layout.size, layout.max_width).iter().enumerate() {
+        let width = text_width(face, line, layout.size);
+        builder.x = match layout.align {
+            TextAlign::Left => layout.x,
+            TextAlign::Center => layout.x - width / 2.0,
+            TextAlign::Right => layout.x - width,
+        };
+        builder.y = layout.y + index as f32 * line_height(layout.size) + leading + ascender;
+
+        for c in line.chars() {
+            let glyph = match face.glyph_index(c) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+            face.outline_glyph(glyph, &mut builder);
+            builder.x += face.glyph_hor_advance(glyph).unwrap_or(0) as f32 * scale;
+        }
+    }
+
+    builder.builder.finish()
+}
+
+/// Adds glyph outlines, in font units with y up, to a path in pixels with y down.
+struct GlyphPathBuilder {
+    builder: PathBuilder,
+    scale: f32,
+    x: f32,
+    y: f32,
+}
+
+impl GlyphPathBuilder {
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.x + x * self.scale, self.y - y * self.scale)
+    }
+}
+
+impl OutlineBuilder for GlyphPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let ((x1, y1), (x, y)) = (self.point(x1, y1), self.point(x, y));
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let ((x1, y1), (x2, y2), (x, y)) =
+            (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        self.builder.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::Url;
+
+use crate::types::{
+    action::ValidatedFrameAction,
+    errors::{Error, ErrorCode, FrameErrors},
+    frame::Frame,
+};
+
+const PAGE_QUERY_PARAM: &str = "page";
+
+pub type FrameHandler =
+    Box<dyn Fn(&ValidatedFrameAction) -> Result<NextFrame, FrameErrors> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NextFrame {
+    /// Render the frame registered for a page.
+    Page(String),
+    /// Render a dynamic frame whose buttons are handled by `page`.
+    Frame { page: String, frame: Frame },
+}
+
+pub struct FrameRouter {
+    pub base_url: String,
+    pages: HashMap<String, Frame>,
+    handlers: HashMap<(String, u32), FrameHandler>,
+}
+
+impl FrameRouter {
+    /// Routes frames posting to `base_url`. Clients only accept absolute post URLs, and
+    /// [`Frame::validate`](crate::types::frame::Frame) rejects relative ones, which are left for
+    /// servers completing them before responding.
+    pub fn new(base_url: &str) -> Self {
+        FrameRouter {
+            base_url: base_url.to_string(),
+            pages: HashMap::new(),
+            handlers: HashMap::new(),
+        }
+    }
+
+    pub fn page(mut self, name: &str, frame: Frame) -> Self {
+        self.pages.insert(name.to_string(), frame);
+        self
+    }
+
+    pub fn handler<F>(mut self, page: &str, button_index: u32, handler: F) -> Self
+    where
+        F: Fn(&ValidatedFrameAction) -> Result<NextFrame, FrameErrors> + Send + Sync + 'static,
+    {
+        self.handlers.insert((page.to_string(), button_index), Box::new(handler));
+        self
+    }
+
+    pub fn page_url(&self, page: &str) -> String {
+        match Url::parse(&self.base_url) {
+            Ok(mut url) => {
+                url.query_pairs_mut().append_pair(PAGE_QUERY_PARAM, page);
+                url.to_string()
+            }
+            // relative base URLs, e.g. `/frames`
+            Err(_) => {
+                let separator = if self.base_url.contains('?') { '&' } else { '?' };
+                let page = utf8_percent_encode(page, NON_ALPHANUMERIC);
+                format!("{}{}{}={}", self.base_url, separator, PAGE_QUERY_PARAM, page)
+            }
+        }
+    }
+
+    /// Reads the page of a URL built with [`FrameRouter::page_url`], absolute or relative.
+    pub fn page_of(url: &str) -> Option<String> {
+        let base = Url::parse("http://localhost/").expect("Invalid Base URL");
+        let url = Url::options().base_url(Some(&base)).parse(url).ok()?;
+        let page = url.query_pairs().find(|(key, _)| key == PAGE_QUERY_PARAM)?;
+        Some(page.1.to_string())
+    }
+
+    pub fn render(&self, page: &str) -> Result<Frame, FrameErrors> {
+        match self.pages.get(page) {
+            Some(frame) => Ok(self.bind(page, frame.clone())),
+            None => Err(router_error(
+                ErrorCode::UnknownPage,
+                format!("No frame is registered for page \"{}\".", page),
+            )),
+        }
+    }
+
+    /// Dispatches an action posted to `request_url` (a URL built with [`FrameRouter::page_url`])
+    /// to the handler registered for its page and button.
+    pub fn dispatch(
+        &self,
+        request_url: &str,
+        action: &ValidatedFrameAction,
+    ) -> Result<Frame, FrameErrors> {
+        let page = match Self::page_of(request_url) {
+            Some(page) => page,
+            None => {
+                return Err(router_error(
+                    ErrorCode::UnknownPage,
+                    format!("The URL \"{}\" does not encode a frame page.", request_url),
+                ))
+            }
+        };
+
+        let handler = match self.handlers.get(&(page.clone(), action.button_index)) {
+            Some(handler) => handler,
+            None => {
+                return Err(router_error(
+                    ErrorCode::UnhandledButton,
+                    format!(
+                        "No handler is registered for button {} on page \"{}\".",
+                        action.button_index, page
+                    ),
+                ))
+            }
+        };
+
+        match handler(action)? {
+            NextFrame::Page(page) => self.render(&page),
+            NextFrame::Frame { page, frame } => Ok(self.bind(&page, frame)),
+        }
+    }
+
+    fn bind(&self, page: &str, mut frame: Frame) -> Frame {
+        frame.post_url = Some(self.page_url(page));
+        frame
+    }
+}
+
+fn router_error(code: ErrorCode, description: String) -> FrameErrors {
+    let mut errors = FrameErrors::new();
+    errors.add_error(Error { description, code, key: Some("fc:frame:post_url".to_string()) });
+    errors
+}
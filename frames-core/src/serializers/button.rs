@@ -2,25 +2,32 @@ use crate::types::button::FrameButton;
 
 use super::HtmlSerializer;
 
-impl HtmlSerializer for FrameButton {
-    fn to_html(&self) -> String {
+impl FrameButton {
+    /// Meta tags of the button under `prefix`, `fc:frame` or `of`.
+    pub(crate) fn meta_tags(&self, prefix: &str) -> String {
         let mut html =
-            format!("<meta name=\"fc:frame:button:{}\" content=\"{}\" />", self.id, self.label);
+            format!("<meta name=\"{}:button:{}\" content=\"{}\" />", prefix, self.id, self.label);
 
         if let Some(action) = &self.action {
             html += &format!(
-                "<meta name=\"fc:frame:button:{}:action\" content=\"{}\" />",
-                self.id, action
+                "<meta name=\"{}:button:{}:action\" content=\"{}\" />",
+                prefix, self.id, action
             );
         }
 
         if let Some(target) = &self.target {
             html += &format!(
-                "<meta name=\"fc:frame:button:{}:target\" content=\"{}\" />",
-                self.id, target
+                "<meta name=\"{}:button:{}:target\" content=\"{}\" />",
+                prefix, self.id, target
             );
         }
 
         html
     }
 }
+
+impl HtmlSerializer for FrameButton {
+    fn to_html(&self) -> String {
+        self.meta_tags("fc:frame")
+    }
+}
@@ -2,24 +2,43 @@ use crate::types::frame::Frame;
 
 use super::HtmlSerializer;
 
-impl HtmlSerializer for Frame {
-    fn to_html(&self) -> String {
-        let mut html = String::new();
-        html += &format!("<title>{}</title>", &self.title);
-        html += &format!("<meta name=\"fc:frame\" content=\"{}\" />", &self.version);
-
-        html += &self.image.to_html();
+impl Frame {
+    /// The image, input, button and post URL tags shared by the `fc:frame` and `of` namespaces.
+    fn meta_tags(&self, prefix: &str) -> String {
+        let mut html = self.image.meta_tags(prefix);
 
         if let Some(input_text) = &self.input_text {
-            html += &format!("<meta name=\"fc:frame:input:text\" content=\"{}\" />", input_text);
+            html += &format!("<meta name=\"{}:input:text\" content=\"{}\" />", prefix, input_text);
         }
 
         for button in self.buttons.iter() {
-            html += &button.to_html()
+            html += &button.meta_tags(prefix)
         }
 
         if let Some(post_url) = &self.post_url {
-            html += &format!("<meta name=\"fc:frame:post_url\" content=\"{}\" />", post_url);
+            html += &format!("<meta name=\"{}:post_url\" content=\"{}\" />", prefix, post_url);
+        }
+
+        html
+    }
+}
+
+impl HtmlSerializer for Frame {
+    fn to_html(&self) -> String {
+        let mut html = String::new();
+        html += &format!("<title>{}</title>", &self.title);
+        html += &format!("<meta name=\"fc:frame\" content=\"{}\" />", &self.version);
+        html += &self.meta_tags("fc:frame");
+
+        if let Some(open_frames) = &self.open_frames {
+            html += &format!("<meta name=\"of:version\" content=\"{}\" />", open_frames.version);
+            for protocol in &open_frames.accepts {
+                html += &format!(
+                    "<meta name=\"of:accepts:{}\" content=\"{}\" />",
+                    protocol.id, protocol.version
+                );
+            }
+            html += &self.meta_tags("of");
         }
 
         html.trim().to_string()
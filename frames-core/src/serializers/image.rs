@@ -2,17 +2,24 @@ use crate::types::image::{AspectRatio, FrameImage};
 
 use super::HtmlSerializer;
 
-impl HtmlSerializer for FrameImage {
-    fn to_html(&self) -> String {
-        let mut html = format!("<meta name=\"fc:frame:image\" content=\"{}\" />", self.url);
-        if self.aspect_ratio == AspectRatio::OneToOne
-            || self.aspect_ratio == AspectRatio::OnePointNineToOne
+impl FrameImage {
+    /// Meta tags of the image under `prefix`, `fc:frame` or `of`.
+    pub(crate) fn meta_tags(&self, prefix: &str) -> String {
+        let mut html = format!("<meta name=\"{}:image\" content=\"{}\" />", prefix, self.url);
+        if self.aspect_ratio == AspectRatio::OneToOne ||
+            self.aspect_ratio == AspectRatio::OnePointNineToOne
         {
             html += &format!(
-                "<meta name=\"fc:frame:image:aspect_ratio\" content=\"{}\" />",
-                self.aspect_ratio.to_string()
+                "<meta name=\"{}:image:aspect_ratio\" content=\"{}\" />",
+                prefix, self.aspect_ratio
             );
         }
         html
     }
 }
+
+impl HtmlSerializer for FrameImage {
+    fn to_html(&self) -> String {
+        self.meta_tags("fc:frame")
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use ethers::{
+    abi::{self, ParamType, Token},
+    contract::MULTICALL_ADDRESS,
+    providers::{JsonRpcClient, JsonRpcError, Provider, ProviderError, RpcError},
+    types::{Address, Bytes, U256, U64},
+    utils::id,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Recorded chain state answering JSON-RPC requests without a node. A call with `"reverted": true`
+/// fails with its `result` as revert data, and `gas` answers `eth_estimateGas` for the call.
+///
+/// ```json
+/// {
+///   "chainId": 10,
+///   "calls": [{ "to": "0x…", "data": "0x…", "result": "0x…", "gas": "0x5208" }],
+///   "code": { "0x…": "0x…" }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fixtures {
+    pub chain_id: u64,
+    #[serde(default)]
+    pub calls: Vec<FixtureCall>,
+    #[serde(default)]
+    pub code: HashMap<Address, Bytes>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureCall {
+    pub to: Address,
+    pub data: Bytes,
+    pub result: Bytes,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reverted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<U256>,
+}
+
+impl FixtureCall {
+    pub fn new(to: Address, data: impl Into<Bytes>, result: impl Into<Bytes>) -> Self {
+        FixtureCall { to, data: data.into(), result: result.into(), reverted: false, gas: None }
+    }
+}
+
+impl Fixtures {
+    pub fn new(chain_id: u64) -> Self {
+        Fixtures { chain_id, ..Default::default() }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        let json = fs::read_to_string(path.as_ref())
+            .map_err(|e| FixtureError::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FixtureError> {
+        fs::write(path.as_ref(), serde_json::to_string_pretty(self)?)
+            .map_err(|e| FixtureError::Io(format!("{}: {}", path.as_ref().display(), e)))
+    }
+
+    pub fn with_call(
+        mut self,
+        to: Address,
+        data: impl Into<Bytes>,
+        result: impl Into<Bytes>,
+    ) -> Self {
+        self.calls.push(FixtureCall::new(to, data, result));
+        self
+    }
+
+    /// Adds a call reverting with `revert_data`.
+    pub fn with_revert(
+        mut self,
+        to: Address,
+        data: impl Into<Bytes>,
+        revert_data: impl Into<Bytes>,
+    ) -> Self {
+        self.calls.push(FixtureCall { reverted: true, ..FixtureCall::new(to, data, revert_data) });
+        self
+    }
+
+    /// Sets the `eth_estimateGas` answer of a call, adding the call with empty return data when
+    /// it is not recorded yet.
+    pub fn with_gas(mut self, to: Address, data: impl Into<Bytes>, gas: impl Into<U256>) -> Self {
+        self.record_gas(to, data.into(), gas.into());
+        self
+    }
+
+    pub fn with_code(mut self, address: Address, code: impl Into<Bytes>) -> Self {
+        self.code.insert(address, code.into());
+        self
+    }
+
+    fn call(&self, to: Address, data: &Bytes) -> Option<&FixtureCall> {
+        self.calls.iter().find(|call| call.to == to && &call.data == data)
+    }
+
+    fn record(&mut self, call: FixtureCall) {
+        match self.calls.iter_mut().find(|c| c.to == call.to && c.data == call.data) {
+            Some(recorded) => {
+                recorded.result = call.result;
+                recorded.reverted = call.reverted;
+            }
+            None => self.calls.push(call),
+        }
+    }
+
+    fn record_gas(&mut self, to: Address, data: Bytes, gas: U256) {
+        match self.calls.iter_mut().find(|call| call.to == to && call.data == data) {
+            Some(call) => call.gas = Some(gas),
+            None => {
+                self.calls.push(FixtureCall { gas: Some(gas), ..FixtureCall::new(to, data, []) })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FixtureError {
+    /// No fixture answers the request.
+    Missing(String),
+    /// The call reverted, answered like a node would with revert data in the error.
+    Revert(JsonRpcError),
+    Io(String),
+    SerdeJson(serde_json::Error),
+    Provider(ProviderError),
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixtureError::Missing(request) => write!(f, "no fixture for {}", request),
+            FixtureError::Revert(e) => write!(f, "{}", e),
+            FixtureError::Io(e) => write!(f, "failed to access fixtures: {}", e),
+            FixtureError::SerdeJson(e) => write!(f, "{}", e),
+            FixtureError::Provider(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {}
+
+impl From<serde_json::Error> for FixtureError {
+    fn from(e: serde_json::Error) -> Self {
+        FixtureError::SerdeJson(e)
+    }
+}
+
+impl RpcError for FixtureError {
+    fn as_error_response(&self) -> Option<&JsonRpcError> {
+        match self {
+            FixtureError::Revert(e) => Some(e),
+            FixtureError::Provider(e) => e.as_error_response(),
+            _ => None,
+        }
+    }
+
+    fn as_serde_error(&self) -> Option<&serde_json::Error> {
+        match self {
+            FixtureError::SerdeJson(e) => Some(e),
+            FixtureError::Provider(e) => e.as_serde_error(),
+            _ => None,
+        }
+    }
+}
+
+impl From<FixtureError> for ProviderError {
+    fn from(e: FixtureError) -> Self {
+        ProviderError::JsonRpcClientError(Box::new(e))
+    }
+}
+
+/// JSON-RPC transport answering `eth_chainId`, `eth_blockNumber`, `eth_call`, `eth_estimateGas`
+/// and `eth_getCode` from [`Fixtures`]. Multicall3 `aggregate3` calls are unpacked and each inner
+/// call is answered from the fixtures too, reverting the batch like Multicall3 does when a call
+/// which does not allow failure reverts.
+#[derive(Debug, Clone)]
+pub struct FixtureClient {
+    fixtures: Arc<Fixtures>,
+}
+
+impl FixtureClient {
+    pub fn new(fixtures: Fixtures) -> Self {
+        FixtureClient { fixtures: Arc::new(fixtures) }
+    }
+
+    fn respond(&self, method: &str, params: &Value) -> Result<Value, FixtureError> {
+        match method {
+            "eth_chainId" => Ok(json!(U64::from(self.fixtures.chain_id))),
+            "eth_blockNumber" => Ok(json!(U64::zero())),
+            "eth_call" => {
+                let (to, data) = call_params(params)?;
+                if to == MULTICALL_ADDRESS && data.starts_with(&id(AGGREGATE3)) {
+                    return Ok(json!(self.aggregate3(&data)?));
+                }
+                match self.fixtures.call(to, &data) {
+                    Some(call) if call.reverted => Err(revert(&call.result)),
+                    Some(call) => Ok(json!(call.result)),
+                    None => {
+                        Err(FixtureError::Missing(format!("eth_call to {:?} with {}", to, data)))
+                    }
+                }
+            }
+            "eth_estimateGas" => {
+                let (to, data) = call_params(params)?;
+                match self.fixtures.call(to, &data) {
+                    Some(call) if call.reverted => Err(revert(&call.result)),
+                    Some(FixtureCall { gas: Some(gas), .. }) => Ok(json!(gas)),
+                    _ => Err(FixtureError::Missing(format!(
+                        "eth_estimateGas to {:?} with {}",
+                        to, data
+                    ))),
+                }
+            }
+            "eth_getCode" => {
+                let address: Address = serde_json::from_value(params[0].clone())?;
+                Ok(json!(self.fixtures.code.get(&address).cloned().unwrap_or_default()))
+            }
+            _ => Err(FixtureError::Missing(method.to_string())),
+        }
+    }
+
+    fn aggregate3(&self, data: &Bytes) -> Result<Bytes, FixtureError> {
+        let call = ParamType::Tuple(vec![ParamType::Address, ParamType::Bool, ParamType::Bytes]);
+        let tokens = abi::decode(&[ParamType::Array(Box::new(call))], &data[4..])
+            .map_err(|e| FixtureError::Missing(format!("aggregate3 with {}: {}", data, e)))?;
+
+        let mut results = Vec::new();
+        for call in tokens.into_iter().next().and_then(Token::into_array).unwrap_or_default() {
+            let (to, allow_failure, data) = match call.into_tuple().as_deref() {
+                Some([Token::Address(to), Token::Bool(allow_failure), Token::Bytes(data)]) => {
+                    (*to, *allow_failure, Bytes::from(data.clone()))
+                }
+                _ => return Err(FixtureError::Missing(format!("aggregate3 call in {}", data))),
+            };
+            let (success, result) = match self.fixtures.call(to, &data) {
+                Some(call) if call.reverted && !allow_failure => {
+                    let reason = Token::String("Multicall3: call failed".to_string());
+                    let mut revert_data = id("Error(string)").to_vec();
+                    revert_data.extend(abi::encode(&[reason]));
+                    return Err(revert(&revert_data.into()));
+                }
+                Some(call) => (!call.reverted, call.result.to_vec()),
+                None => {
+                    return Err(FixtureError::Missing(format!("eth_call to {:?} with {}", to, data)))
+                }
+            };
+            results.push(Token::Tuple(vec![Token::Bool(success), Token::Bytes(result)]));
+        }
+
+        Ok(abi::encode(&[Token::Array(results)]).into())
+    }
+}
+
+const AGGREGATE3: &str = "aggregate3((address,bool,bytes)[])";
+
+#[async_trait]
+impl JsonRpcClient for FixtureClient {
+    type Error = FixtureError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: fmt::Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let result = self.respond(method, &serde_json::to_value(params)?)?;
+        Ok(serde_json::from_value(result)?)
+    }
+}
+
+/// Middleware answering from [`Fixtures`], usable wherever a `Middleware` is expected.
+pub type MockMiddleware = Provider<FixtureClient>;
+
+pub fn mock_middleware(fixtures: Fixtures) -> MockMiddleware {
+    Provider::new(FixtureClient::new(fixtures))
+}
+
+/// JSON-RPC transport forwarding to `inner` and recording every `eth_call`, `eth_estimateGas`
+/// and `eth_getCode`, reverts included, into [`Fixtures`], to be saved and replayed later with
+/// [`FixtureClient`].
+#[derive(Debug, Clone)]
+pub struct RecordingClient<C: JsonRpcClient> {
+    inner: C,
+    fixtures: Arc<Mutex<Fixtures>>,
+}
+
+impl<C: JsonRpcClient> RecordingClient<C> {
+    pub fn new(inner: C, chain_id: u64) -> Self {
+        RecordingClient { inner, fixtures: Arc::new(Mutex::new(Fixtures::new(chain_id))) }
+    }
+
+    pub fn fixtures(&self) -> Fixtures {
+        self.fixtures.lock().expect("Fixtures Lock Error").clone()
+    }
+}
+
+#[async_trait]
+impl<C: JsonRpcClient> JsonRpcClient for RecordingClient<C> {
+    type Error = FixtureError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: fmt::Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let params = serde_json::to_value(params)?;
+        let result: Value = match self.inner.request(method, params.clone()).await {
+            Ok(result) => result,
+            Err(e) => {
+                let revert_data = e.as_error_response().and_then(|e| e.as_revert_data());
+                if let (Some(revert_data), "eth_call") = (revert_data, method) {
+                    let (to, data) = call_params(&params)?;
+                    self.fixtures.lock().expect("Fixtures Lock Error").record(FixtureCall {
+                        reverted: true,
+                        ..FixtureCall::new(to, data, revert_data)
+                    });
+                }
+                return Err(FixtureError::Provider(e.into()));
+            }
+        };
+
+        match method {
+            "eth_call" => {
+                let (to, data) = call_params(&params)?;
+                let result: Bytes = serde_json::from_value(result.clone())?;
+                self.fixtures
+                    .lock()
+                    .expect("Fixtures Lock Error")
+                    .record(FixtureCall::new(to, data, result));
+            }
+            "eth_estimateGas" => {
+                let (to, data) = call_params(&params)?;
+                let gas: U256 = serde_json::from_value(result.clone())?;
+                self.fixtures.lock().expect("Fixtures Lock Error").record_gas(to, data, gas);
+            }
+            "eth_getCode" => {
+                let address: Address = serde_json::from_value(params[0].clone())?;
+                let code: Bytes = serde_json::from_value(result.clone())?;
+                self.fixtures.lock().expect("Fixtures Lock Error").code.insert(address, code);
+            }
+            _ => {}
+        }
+
+        Ok(serde_json::from_value(result)?)
+    }
+}
+
+fn revert(data: &Bytes) -> FixtureError {
+    FixtureError::Revert(JsonRpcError {
+        code: 3,
+        message: "execution reverted".to_string(),
+        data: Some(json!(data)),
+    })
+}
+
+fn call_params(params: &Value) -> Result<(Address, Bytes), FixtureError> {
+    let tx = &params[0];
+    let to: Address = serde_json::from_value(tx["to"].clone())?;
+    let data = if tx["data"].is_null() { tx["input"].clone() } else { tx["data"].clone() };
+    let data: Bytes = if data.is_null() { Bytes::default() } else { serde_json::from_value(data)? };
+    Ok((to, data))
+}
@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+type Routes = Arc<Mutex<HashMap<String, (u16, String)>>>;
+
+/// Local HTTP server answering `GET` requests with canned JSON bodies, keyed by path and query
+/// (e.g. `/v1/userDataByFid?fid=2`).
+pub struct HttpStub {
+    pub url: String,
+    routes: Routes,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl HttpStub {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("Bind HTTP Stub Error");
+        let url = format!("http://{}", listener.local_addr().expect("HTTP Stub Address Error"));
+        let routes: Routes = Arc::new(Mutex::new(HashMap::new()));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (server_routes, server_requests) = (routes.clone(), requests.clone());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut head = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => head.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let head = String::from_utf8_lossy(&head);
+                let target = head.split_whitespace().nth(1).unwrap_or("/").to_string();
+                server_requests.lock().expect("HTTP Stub Lock Error").push(target.clone());
+                let (status, body) = server_routes
+                    .lock()
+                    .expect("HTTP Stub Lock Error")
+                    .get(&target)
+                    .cloned()
+                    .unwrap_or((404, "{}".to_string()));
+
+                let response = format!(
+                    "HTTP/1.1 {} STUB\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        HttpStub { url, routes, requests }
+    }
+
+    pub fn route(&self, path_and_query: &str, status: u16, body: &str) -> &Self {
+        self.routes
+            .lock()
+            .expect("HTTP Stub Lock Error")
+            .insert(path_and_query.to_string(), (status, body.to_string()));
+        self
+    }
+
+    /// Paths and queries requested so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().expect("HTTP Stub Lock Error").clone()
+    }
+}
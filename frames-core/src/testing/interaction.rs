@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use crate::{provider::interaction::InteractionProvider, types::action::ActionCastId};
+
+/// [`InteractionProvider`] answering from a fixed set of likes, recasts and follows. Anything
+/// not added is reported as missing.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureInteractions {
+    likes: HashSet<(u64, u64, String)>,
+    recasts: HashSet<(u64, u64, String)>,
+    follows: HashSet<(u64, u64)>,
+}
+
+impl FixtureInteractions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_like(mut self, fid: u64, cast_id: &ActionCastId) -> Self {
+        self.likes.insert(reaction(fid, cast_id));
+        self
+    }
+
+    pub fn with_recast(mut self, fid: u64, cast_id: &ActionCastId) -> Self {
+        self.recasts.insert(reaction(fid, cast_id));
+        self
+    }
+
+    pub fn with_follow(mut self, fid: u64, target_fid: u64) -> Self {
+        self.follows.insert((fid, target_fid));
+        self
+    }
+}
+
+#[async_trait]
+impl InteractionProvider for FixtureInteractions {
+    async fn has_liked(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.likes.contains(&reaction(fid, cast_id)))
+    }
+
+    async fn has_recasted(
+        &self,
+        fid: u64,
+        cast_id: &ActionCastId,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.recasts.contains(&reaction(fid, cast_id)))
+    }
+
+    async fn follows(&self, fid: u64, target_fid: u64) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.follows.contains(&(fid, target_fid)))
+    }
+}
+
+fn reaction(fid: u64, cast_id: &ActionCastId) -> (u64, u64, String) {
+    (fid, cast_id.fid, cast_id.hash.to_lowercase())
+}
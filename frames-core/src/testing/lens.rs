@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ethers::{
+    signers::{LocalWallet, Signer},
+    types::{
+        transaction::eip712::{Eip712, TypedData},
+        Address, H256, U256,
+    },
+};
+
+use crate::{
+    provider::lens::LensProfileProvider,
+    types::{
+        external::PayloadTrustedData,
+        lens::{LensDomain, LensFrameActionPayload, LensUntrustedData},
+    },
+};
+
+/// Wallet signing Lens frame actions that pass [`LensFrameActionPayload::verify`] for `domain`
+/// when the signer itself is the [`LensProfileProvider`], which treats its wallet as the owner
+/// of every profile.
+pub struct LensTestSigner {
+    pub domain: LensDomain,
+    wallet: LocalWallet,
+}
+
+impl LensTestSigner {
+    /// Creates a signer with a key derived from `seed` for a `FrameVerifier` on Polygon.
+    pub fn new(seed: u64) -> Self {
+        let secret = blake3::hash(format!("frames-rs lens {}", seed).as_bytes());
+        LensTestSigner {
+            domain: LensDomain::new(137, Address::repeat_byte(0x1e)),
+            wallet: LocalWallet::from_bytes(secret.as_bytes()).expect("Invalid Secret Key"),
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        self.wallet.address()
+    }
+
+    pub fn action(&self, url: &str) -> LensTestFrameAction<'_> {
+        LensTestFrameAction {
+            signer: self,
+            data: LensUntrustedData {
+                spec_version: "1.0.0".to_string(),
+                profile_id: "0x01".to_string(),
+                pub_id: "0x01-0x01".to_string(),
+                url: url.to_string(),
+                button_index: 1,
+                unix_timestamp: Utc::now().timestamp(),
+                deadline: Some(Utc::now().timestamp() + 600),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl LensProfileProvider for LensTestSigner {
+    async fn is_profile_signer(
+        &self,
+        _profile_id: U256,
+        signer: Address,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(signer == self.address())
+    }
+}
+
+pub struct LensTestFrameAction<'a> {
+    signer: &'a LensTestSigner,
+    data: LensUntrustedData,
+}
+
+impl LensTestFrameAction<'_> {
+    pub fn profile_id(mut self, profile_id: &str) -> Self {
+        self.data.profile_id = profile_id.to_string();
+        self
+    }
+
+    pub fn pub_id(mut self, pub_id: &str) -> Self {
+        self.data.pub_id = pub_id.to_string();
+        self
+    }
+
+    pub fn button_index(mut self, button_index: u32) -> Self {
+        self.data.button_index = button_index;
+        self
+    }
+
+    pub fn input_text(mut self, input_text: &str) -> Self {
+        self.data.input_text = Some(input_text.to_string());
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        self.data.state = Some(state.to_string());
+        self
+    }
+
+    pub fn action_response(mut self, transaction_hash: &str) -> Self {
+        self.data.action_response = Some(transaction_hash.to_string());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.data.unix_timestamp = timestamp.timestamp();
+        self
+    }
+
+    pub fn deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.data.deadline = Some(deadline.timestamp());
+        self
+    }
+
+    pub fn payload(&self) -> LensFrameActionPayload {
+        let typed_data: TypedData =
+            serde_json::from_value(self.data.typed_data(&self.signer.domain))
+                .expect("Invalid Typed Data");
+        let hash = typed_data.encode_eip712().expect("Invalid Typed Data");
+        let signature = self.signer.wallet.sign_hash(H256(hash)).expect("Sign Error");
+
+        LensFrameActionPayload {
+            client_protocol: "lens@1.0.0".to_string(),
+            trusted_data: PayloadTrustedData { message_bytes: format!("0x{}", signature) },
+            untrusted_data: self.data.clone(),
+        }
+    }
+
+    pub fn json(&self) -> String {
+        serde_json::to_string(&self.payload()).expect("Serialize Payload Error")
+    }
+}
@@ -0,0 +1,9 @@
+pub mod evm;
+pub mod http;
+pub mod interaction;
+#[cfg(feature = "lens")]
+pub mod lens;
+pub mod profile;
+pub mod signer;
+#[cfg(feature = "xmtp")]
+pub mod xmtp;
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::{
+    provider::profile::ProfileProvider, testing::evm::FixtureError, types::profile::UserProfile,
+};
+
+/// [`ProfileProvider`] answering from a fixed set of profiles.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureProfiles {
+    profiles: HashMap<u64, UserProfile>,
+}
+
+impl FixtureProfiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_profile(mut self, profile: UserProfile) -> Self {
+        self.profiles.insert(profile.fid, profile);
+        self
+    }
+}
+
+#[async_trait]
+impl ProfileProvider for FixtureProfiles {
+    async fn get_user_profile(&self, fid: u64) -> Result<UserProfile, Box<dyn std::error::Error>> {
+        match self.profiles.get(&fid) {
+            Some(profile) => Ok(profile.clone()),
+            None => Err(Box::new(FixtureError::Missing(format!("profile of fid {}", fid)))),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signer, SigningKey};
+use prost::Message as ProstMessage;
+
+use crate::{
+    provider::signer::SignerProvider,
+    types::{
+        external::{
+            CastActionPayload, CastActionUntrustedData, ComposerActionPayload,
+            ComposerActionUntrustedData, FrameActionPayload, PayloadCastId, PayloadTrustedData,
+            PayloadUntrustedData,
+        },
+        message::{
+            CastId, FarcasterNetwork, FrameActionBody, HashScheme, Message, MessageBody,
+            MessageData, MessageType, SignatureScheme, FARCASTER_EPOCH,
+        },
+    },
+};
+
+/// Ed25519 signer producing frame action messages that pass [`FrameActionPayload::verify`]
+/// when the signer itself is the [`SignerProvider`], which only knows its own key.
+pub struct TestSigner {
+    pub fid: u64,
+    key: SigningKey,
+}
+
+impl TestSigner {
+    /// Creates a signer with a key derived from `fid`, so the same fid always signs with the
+    /// same key.
+    pub fn new(fid: u64) -> Self {
+        let seed = blake3::hash(format!("frames-rs test signer {}", fid).as_bytes());
+        Self::from_secret_key(fid, *seed.as_bytes())
+    }
+
+    pub fn from_secret_key(fid: u64, secret_key: [u8; 32]) -> Self {
+        TestSigner { fid, key: SigningKey::from_bytes(&secret_key) }
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    pub fn action(&self, url: &str) -> TestFrameAction<'_> {
+        TestFrameAction {
+            signer: self,
+            url: url.to_string(),
+            button_index: 1,
+            input_text: None,
+            state: None,
+            cast_id: None,
+            transaction_id: None,
+            address: None,
+            timestamp: Utc::now(),
+            network: FarcasterNetwork::Mainnet,
+        }
+    }
+}
+
+#[async_trait]
+impl SignerProvider for TestSigner {
+    async fn is_active_signer(
+        &self,
+        fid: u64,
+        signer: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(fid == self.fid && signer == self.public_key())
+    }
+}
+
+pub struct TestFrameAction<'a> {
+    signer: &'a TestSigner,
+    url: String,
+    button_index: u32,
+    input_text: Option<String>,
+    state: Option<String>,
+    cast_id: Option<CastId>,
+    transaction_id: Option<Vec<u8>>,
+    address: Option<Vec<u8>>,
+    timestamp: DateTime<Utc>,
+    network: FarcasterNetwork,
+}
+
+impl TestFrameAction<'_> {
+    pub fn button_index(mut self, button_index: u32) -> Self {
+        self.button_index = button_index;
+        self
+    }
+
+    pub fn input_text(mut self, input_text: &str) -> Self {
+        self.input_text = Some(input_text.to_string());
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        self.state = Some(state.to_string());
+        self
+    }
+
+    /// Sets the cast the frame was embedded in, `hash` is a `0x` prefixed hex string.
+    pub fn cast_id(mut self, fid: u64, hash: &str) -> Self {
+        let hash = hex::decode(hash.trim_start_matches("0x")).expect("Invalid Cast Hash");
+        self.cast_id = Some(CastId { fid, hash });
+        self
+    }
+
+    pub fn transaction_id(mut self, transaction_id: &str) -> Self {
+        self.transaction_id =
+            Some(hex::decode(transaction_id.trim_start_matches("0x")).expect("Invalid Tx Hash"));
+        self
+    }
+
+    pub fn address(mut self, address: &str) -> Self {
+        self.address =
+            Some(hex::decode(address.trim_start_matches("0x")).expect("Invalid Address"));
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn network(mut self, network: FarcasterNetwork) -> Self {
+        self.network = network;
+        self
+    }
+
+    pub fn message(&self) -> Message {
+        let cast_id = self
+            .cast_id
+            .clone()
+            .unwrap_or_else(|| CastId { fid: self.signer.fid, hash: vec![0u8; 20] });
+        let data = MessageData {
+            r#type: MessageType::FrameAction as i32,
+            fid: self.signer.fid,
+            timestamp: ((self.timestamp.timestamp_millis() - FARCASTER_EPOCH) / 1000) as u32,
+            network: self.network as i32,
+            body: Some(MessageBody::FrameActionBody(FrameActionBody {
+                url: self.url.as_bytes().to_vec(),
+                button_index: self.button_index,
+                cast_id: Some(cast_id),
+                input_text: self.input_text.clone().unwrap_or_default().into_bytes(),
+                state: self.state.clone().unwrap_or_default().into_bytes(),
+                transaction_id: self.transaction_id.clone().unwrap_or_default(),
+                address: self.address.clone().unwrap_or_default(),
+            })),
+        };
+        let data_bytes = data.encode_to_vec();
+        let hash = blake3::hash(&data_bytes).as_bytes()[..20].to_vec();
+
+        Message {
+            data: Some(data),
+            signature: self.signer.key.sign(&hash).to_bytes().to_vec(),
+            hash,
+            hash_scheme: HashScheme::Blake3 as i32,
+            signature_scheme: SignatureScheme::Ed25519 as i32,
+            signer: self.signer.public_key().to_vec(),
+            data_bytes: Some(data_bytes),
+        }
+    }
+
+    pub fn message_bytes(&self) -> Vec<u8> {
+        self.message().encode_to_vec()
+    }
+
+    pub fn payload(&self) -> FrameActionPayload {
+        let message = self.message();
+        let cast_id = match message.data.as_ref().and_then(|data| data.body.as_ref()) {
+            Some(MessageBody::FrameActionBody(body)) => body.cast_id.clone().unwrap_or_default(),
+            _ => CastId::default(),
+        };
+
+        FrameActionPayload {
+            trusted_data: PayloadTrustedData {
+                message_bytes: hex::encode(message.encode_to_vec()),
+            },
+            untrusted_data: PayloadUntrustedData {
+                fid: self.signer.fid as i32,
+                url: self.url.clone(),
+                message_hash: format!("0x{}", hex::encode(&message.hash)),
+                timestamp: self.timestamp,
+                network: self.network as i32,
+                button_index: self.button_index as i32,
+                cast_id: PayloadCastId {
+                    fid: cast_id.fid as i32,
+                    hash: format!("0x{}", hex::encode(&cast_id.hash)),
+                },
+                input_text: self.input_text.clone(),
+                state: self.state.clone(),
+                transaction_id: self
+                    .transaction_id
+                    .as_ref()
+                    .map(|id| format!("0x{}", hex::encode(id))),
+                address: self.address.as_ref().map(|address| format!("0x{}", hex::encode(address))),
+            },
+        }
+    }
+
+    /// The payload of a cast action invoked on the cast set with [`Self::cast_id`].
+    pub fn cast_action_payload(&self) -> CastActionPayload {
+        let payload = self.payload();
+        let data = payload.untrusted_data;
+        CastActionPayload {
+            trusted_data: payload.trusted_data,
+            untrusted_data: CastActionUntrustedData {
+                fid: data.fid,
+                url: data.url,
+                message_hash: data.message_hash,
+                timestamp: data.timestamp,
+                network: data.network,
+                button_index: data.button_index,
+                cast_id: data.cast_id,
+            },
+        }
+    }
+
+    /// The payload of a composer action, with the draft encoded by
+    /// [`ComposerActionState::encode`](crate::types::composer_action::ComposerActionState::encode)
+    /// set as [`Self::state`].
+    pub fn composer_action_payload(&self) -> ComposerActionPayload {
+        let payload = self.payload();
+        let data = payload.untrusted_data;
+        ComposerActionPayload {
+            trusted_data: payload.trusted_data,
+            untrusted_data: ComposerActionUntrustedData {
+                fid: data.fid,
+                url: data.url,
+                message_hash: data.message_hash,
+                timestamp: data.timestamp,
+                network: data.network,
+                button_index: data.button_index,
+                state: data.state.unwrap_or_default(),
+            },
+        }
+    }
+
+    pub fn json(&self) -> String {
+        serde_json::to_string(&self.payload()).expect("Serialize Payload Error")
+    }
+}
@@ -0,0 +1,188 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use ethers::{
+    signers::{LocalWallet, Signer},
+    types::{Address, Signature as EcdsaSignature, H256},
+    utils::hash_message,
+};
+use prost::Message as ProstMessage;
+use sha2::{Digest, Sha256};
+
+use crate::types::{
+    external::PayloadTrustedData,
+    xmtp::{
+        identity_signature_text, FrameAction, FrameActionBody, PublicKeyUnion,
+        RecoverableSignature, Secp256k1Uncompressed, Signature, SignatureUnion, SignedPublicKey,
+        SignedPublicKeyBundle, UnsignedPublicKey, XmtpFrameActionPayload, XmtpUntrustedData,
+    },
+};
+
+/// XMTP wallet and identity key producing frame actions that pass
+/// [`XmtpFrameActionPayload::verify`].
+pub struct XmtpTestSigner {
+    wallet: LocalWallet,
+    identity: LocalWallet,
+}
+
+impl XmtpTestSigner {
+    /// Creates a signer with keys derived from `seed`, so the same seed always signs with the
+    /// same wallet.
+    pub fn new(seed: u64) -> Self {
+        let key = |purpose: &str| {
+            let secret = blake3::hash(format!("frames-rs xmtp {} {}", purpose, seed).as_bytes());
+            LocalWallet::from_bytes(secret.as_bytes()).expect("Invalid Secret Key")
+        };
+        XmtpTestSigner { wallet: key("wallet"), identity: key("identity") }
+    }
+
+    pub fn wallet_address(&self) -> Address {
+        self.wallet.address()
+    }
+
+    pub fn identity_address(&self) -> Address {
+        self.identity.address()
+    }
+
+    /// The identity key, signed by the wallet.
+    pub fn identity_key(&self) -> SignedPublicKey {
+        let point = self.identity.signer().verifying_key().to_encoded_point(false);
+        let key_bytes = UnsignedPublicKey {
+            created_ns: 0,
+            union: Some(PublicKeyUnion::Secp256k1Uncompressed(Secp256k1Uncompressed {
+                bytes: point.as_bytes().to_vec(),
+            })),
+        }
+        .encode_to_vec();
+        let signature = self
+            .wallet
+            .sign_hash(hash_message(identity_signature_text(&key_bytes)))
+            .expect("Sign Error");
+
+        SignedPublicKey {
+            key_bytes,
+            signature: Some(Signature {
+                union: Some(SignatureUnion::WalletEcdsaCompact(compact(signature))),
+            }),
+        }
+    }
+
+    pub fn action(&self, url: &str) -> XmtpTestFrameAction<'_> {
+        XmtpTestFrameAction {
+            signer: self,
+            body: FrameActionBody {
+                frame_url: url.to_string(),
+                button_index: 1,
+                opaque_conversation_identifier: "conversation".to_string(),
+                ..Default::default()
+            },
+            timestamp: Utc::now(),
+            identity_key: None,
+        }
+    }
+}
+
+pub struct XmtpTestFrameAction<'a> {
+    signer: &'a XmtpTestSigner,
+    body: FrameActionBody,
+    timestamp: DateTime<Utc>,
+    identity_key: Option<SignedPublicKey>,
+}
+
+impl XmtpTestFrameAction<'_> {
+    pub fn button_index(mut self, button_index: i32) -> Self {
+        self.body.button_index = button_index;
+        self
+    }
+
+    pub fn input_text(mut self, input_text: &str) -> Self {
+        self.body.input_text = input_text.to_string();
+        self
+    }
+
+    pub fn state(mut self, state: &str) -> Self {
+        self.body.state = state.to_string();
+        self
+    }
+
+    pub fn transaction_id(mut self, transaction_id: &str) -> Self {
+        self.body.transaction_id = transaction_id.to_string();
+        self
+    }
+
+    pub fn address(mut self, address: &str) -> Self {
+        self.body.address = address.to_string();
+        self
+    }
+
+    pub fn conversation(mut self, opaque_conversation_identifier: &str) -> Self {
+        self.body.opaque_conversation_identifier = opaque_conversation_identifier.to_string();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Attaches another identity key, e.g. one registered by a different wallet.
+    pub fn identity_key(mut self, identity_key: SignedPublicKey) -> Self {
+        self.identity_key = Some(identity_key);
+        self
+    }
+
+    pub fn action(&self) -> FrameAction {
+        let body = FrameActionBody {
+            timestamp: self.timestamp.timestamp_millis() as u64,
+            unix_timestamp: self.timestamp.timestamp() as u32,
+            ..self.body.clone()
+        };
+        let action_body = body.encode_to_vec();
+        let digest: [u8; 32] = Sha256::digest(&action_body).into();
+        let signature = self.signer.identity.sign_hash(H256(digest)).expect("Sign Error");
+
+        FrameAction {
+            signature: Some(Signature {
+                union: Some(SignatureUnion::EcdsaCompact(compact(signature))),
+            }),
+            signed_public_key_bundle: Some(SignedPublicKeyBundle {
+                identity_key: Some(
+                    self.identity_key.clone().unwrap_or_else(|| self.signer.identity_key()),
+                ),
+                pre_key: None,
+            }),
+            action_body,
+        }
+    }
+
+    pub fn payload(&self) -> XmtpFrameActionPayload {
+        let non_empty = |value: &str| Some(value.to_string()).filter(|value| !value.is_empty());
+
+        XmtpFrameActionPayload {
+            client_protocol: "xmtp@2024-02-09".to_string(),
+            trusted_data: PayloadTrustedData {
+                message_bytes: STANDARD.encode(self.action().encode_to_vec()),
+            },
+            untrusted_data: XmtpUntrustedData {
+                wallet_address: format!("{:?}", self.signer.wallet_address()),
+                url: self.body.frame_url.clone(),
+                timestamp: self.timestamp,
+                button_index: self.body.button_index,
+                input_text: non_empty(&self.body.input_text),
+                state: non_empty(&self.body.state),
+                transaction_id: non_empty(&self.body.transaction_id),
+                address: non_empty(&self.body.address),
+                opaque_conversation_identifier: self.body.opaque_conversation_identifier.clone(),
+                unix_timestamp: self.timestamp.timestamp() as u32,
+            },
+        }
+    }
+
+    pub fn json(&self) -> String {
+        serde_json::to_string(&self.payload()).expect("Serialize Payload Error")
+    }
+}
+
+fn compact(signature: EcdsaSignature) -> RecoverableSignature {
+    let bytes: [u8; 65] = signature.into();
+    RecoverableSignature { bytes: bytes[..64].to_vec(), recovery: (bytes[64] - 27) as u32 }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionCastId {
+    pub fid: u64,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatedFrameAction {
+    pub fid: u64,
+    pub url: String,
+    pub button_index: u32,
+    pub input_text: Option<String>,
+    pub state: Option<String>,
+    pub cast_id: Option<ActionCastId>,
+    pub transaction_id: Option<String>,
+    pub address: Option<String>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub network: i32,
+    pub message_hash: String,
+    pub signer: String,
+}
+
+/// Identity of whoever pressed the button, in the namespace of the client's protocol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "protocol", rename_all = "lowercase", rename_all_fields = "camelCase")]
+pub enum Actor {
+    Farcaster {
+        fid: u64,
+        /// Cast the frame was embedded in.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cast_id: Option<ActionCastId>,
+    },
+    Xmtp {
+        wallet_address: String,
+        /// Conversation the frame was sent in, opaque to the frame.
+        opaque_conversation_identifier: String,
+    },
+    Lens {
+        profile_id: String,
+        /// `<profile id>-<publication id>` of the publication embedding the frame.
+        pub_id: String,
+    },
+}
+
+impl Actor {
+    /// The Open Frames protocol identifier, as in `of:accepts:<protocol>`.
+    pub fn protocol(&self) -> &'static str {
+        match self {
+            Actor::Farcaster { .. } => "farcaster",
+            Actor::Xmtp { .. } => "xmtp",
+            Actor::Lens { .. } => "lens",
+        }
+    }
+
+    pub fn fid(&self) -> Option<u64> {
+        match self {
+            Actor::Farcaster { fid, .. } => Some(*fid),
+            _ => None,
+        }
+    }
+}
+
+/// A verified frame action, whichever protocol the client used to sign it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameAction {
+    pub actor: Actor,
+    pub url: String,
+    pub button_index: u32,
+    pub input_text: Option<String>,
+    pub state: Option<String>,
+    pub transaction_id: Option<String>,
+    pub address: Option<String>,
+    /// When the action was signed, or verified for Lens actions, which only sign a deadline.
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Signed deadline after which the signature is no longer valid, only set by Lens actions.
+    #[serde(
+        default,
+        with = "chrono::serde::ts_milliseconds_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub message_hash: String,
+    pub signer: String,
+}
+
+impl From<ValidatedFrameAction> for FrameAction {
+    fn from(action: ValidatedFrameAction) -> Self {
+        FrameAction {
+            actor: Actor::Farcaster { fid: action.fid, cast_id: action.cast_id },
+            url: action.url,
+            button_index: action.button_index,
+            input_text: action.input_text,
+            state: action.state,
+            transaction_id: action.transaction_id,
+            address: action.address,
+            timestamp: action.timestamp,
+            expires_at: None,
+            message_hash: action.message_hash,
+            signer: action.signer,
+        }
+    }
+}
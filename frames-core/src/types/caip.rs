@@ -0,0 +1,69 @@
+use std::{fmt, str::FromStr};
+
+use ethers::types::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// CAIP-10 account id of an EVM contract, as used by `mint` button targets:
+/// `eip155:<chain id>:<address>[:<token id>]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Caip10Address {
+    pub chain_id: u64,
+    pub address: Address,
+    pub token_id: Option<U256>,
+}
+
+impl FromStr for Caip10Address {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (namespace, chain_id, address, token_id) = match parts.as_slice() {
+            [namespace, chain_id, address] => (namespace, chain_id, address, None),
+            [namespace, chain_id, address, token_id] => {
+                (namespace, chain_id, address, Some(token_id))
+            }
+            _ => return Err(format!("{} is not a CAIP-10 address.", value)),
+        };
+
+        let chain_id = parse_chain_id(namespace, chain_id)?;
+        let address = match address.strip_prefix("0x") {
+            Some(hex) if hex.len() == 40 => address.parse().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("{} is not a valid address.", address))?;
+        let token_id = token_id
+            .map(|token_id| {
+                U256::from_dec_str(token_id)
+                    .map_err(|_| format!("{} is not a valid token id.", token_id))
+            })
+            .transpose()?;
+
+        Ok(Caip10Address { chain_id, address, token_id })
+    }
+}
+
+/// Parses the chain id of an `eip155:<chain id>` CAIP-2 identifier.
+pub fn eip155_chain_id(value: &str) -> Result<u64, String> {
+    match value.split_once(':') {
+        Some((namespace, chain_id)) => parse_chain_id(namespace, chain_id),
+        None => Err(format!("{} is not a CAIP-2 chain id.", value)),
+    }
+}
+
+fn parse_chain_id(namespace: &str, chain_id: &str) -> Result<u64, String> {
+    if namespace != "eip155" {
+        return Err(format!("Unsupported CAIP-2 namespace {}, expected eip155.", namespace));
+    }
+    chain_id.parse().map_err(|_| format!("{} is not a valid chain id.", chain_id))
+}
+
+impl fmt::Display for Caip10Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "eip155:{}:{:?}", self.chain_id, self.address)?;
+        if let Some(token_id) = self.token_id {
+            write!(f, ":{}", token_id)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Cast actions: buttons users install in their client to act on any cast. Clients read
+//! [`CastActionMetadata`] with a GET to the action URL, then POST a signed
+//! [`CastActionPayload`](crate::types::external::CastActionPayload) to it and show the
+//! [`CastActionResponse`].
+
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::types::action::ActionCastId;
+
+pub const ADD_CAST_ACTION_URL: &str = "https://warpcast.com/~/add-cast-action";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CastActionMetadata {
+    /// Up to 20 characters.
+    pub name: String,
+    /// Octicon name, e.g. `heart`.
+    pub icon: String,
+    /// Up to 80 characters.
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about_url: Option<String>,
+    pub action: ActionDefinition,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionDefinition {
+    #[serde(rename = "type")]
+    pub action_type: ActionType,
+    /// URL the client POSTs to, when it is not the URL the metadata was read from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionType {
+    Post,
+    Composer,
+}
+
+impl CastActionMetadata {
+    pub fn new(name: &str, icon: &str, description: &str) -> Self {
+        CastActionMetadata {
+            name: name.to_string(),
+            icon: icon.to_string(),
+            description: description.to_string(),
+            about_url: None,
+            action: ActionDefinition { action_type: ActionType::Post, post_url: None },
+        }
+    }
+
+    pub fn about_url(mut self, about_url: &str) -> Self {
+        self.about_url = Some(about_url.to_string());
+        self
+    }
+
+    pub fn post_url(mut self, post_url: &str) -> Self {
+        self.action.post_url = Some(post_url.to_string());
+        self
+    }
+}
+
+/// Link that prompts the user to install the cast action whose metadata is served at
+/// `metadata_url`.
+pub fn install_url(metadata_url: &str) -> String {
+    let mut url = Url::parse(ADD_CAST_ACTION_URL).expect("Invalid Add Cast Action URL");
+    url.query_pairs_mut().append_pair("url", metadata_url);
+    url.to_string()
+}
+
+/// Response to a cast action POST: a toast shown to the user, or a frame opened in a modal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", rename_all_fields = "camelCase")]
+pub enum CastActionResponse {
+    Message {
+        /// Up to 80 characters.
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        link: Option<String>,
+    },
+    Frame {
+        frame_url: String,
+    },
+}
+
+impl CastActionResponse {
+    pub fn message(message: &str) -> Self {
+        CastActionResponse::Message { message: message.to_string(), link: None }
+    }
+
+    pub fn message_with_link(message: &str, link: &str) -> Self {
+        CastActionResponse::Message { message: message.to_string(), link: Some(link.to_string()) }
+    }
+
+    pub fn frame(frame_url: &str) -> Self {
+        CastActionResponse::Frame { frame_url: frame_url.to_string() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatedCastAction {
+    /// The user who invoked the action.
+    pub fid: u64,
+    pub url: String,
+    /// The cast the action was invoked on.
+    pub cast_id: ActionCastId,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub network: i32,
+    pub message_hash: String,
+    pub signer: String,
+}
@@ -0,0 +1,125 @@
+//! Composer actions: cast action variants run from the cast composer. The client POSTs the draft
+//! being composed in a signed [`ComposerActionPayload`] and opens the returned
+//! [`ComposerActionResponse`] form in an app embedded in the composer.
+//!
+//! [`ComposerActionPayload`]: crate::types::external::ComposerActionPayload
+
+use chrono::{DateTime, Utc};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+use crate::types::cast_action::{ActionDefinition, ActionType};
+
+/// Characters left alone by JavaScript's `encodeURIComponent`, which clients encode state with.
+const URI_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerActionMetadata {
+    /// Always [`ActionType::Composer`].
+    #[serde(rename = "type")]
+    pub action_type: ActionType,
+    /// Up to 20 characters.
+    pub name: String,
+    /// Octicon name, e.g. `checkbox`.
+    pub icon: String,
+    /// Up to 80 characters.
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about_url: Option<String>,
+    /// Image shown when the action is listed in the client.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    pub action: ActionDefinition,
+}
+
+impl ComposerActionMetadata {
+    pub fn new(name: &str, icon: &str, description: &str) -> Self {
+        ComposerActionMetadata {
+            action_type: ActionType::Composer,
+            name: name.to_string(),
+            icon: icon.to_string(),
+            description: description.to_string(),
+            about_url: None,
+            image_url: None,
+            action: ActionDefinition { action_type: ActionType::Post, post_url: None },
+        }
+    }
+
+    pub fn about_url(mut self, about_url: &str) -> Self {
+        self.about_url = Some(about_url.to_string());
+        self
+    }
+
+    pub fn image_url(mut self, image_url: &str) -> Self {
+        self.image_url = Some(image_url.to_string());
+        self
+    }
+
+    pub fn post_url(mut self, post_url: &str) -> Self {
+        self.action.post_url = Some(post_url.to_string());
+        self
+    }
+}
+
+/// Response to a composer action POST: a page the client opens in a modal over the composer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ComposerActionResponse {
+    Form { title: String, url: String },
+}
+
+impl ComposerActionResponse {
+    pub fn form(title: &str, url: &str) -> Self {
+        ComposerActionResponse::Form { title: title.to_string(), url: url.to_string() }
+    }
+}
+
+/// The signed `state` of a composer action: the cast being composed when the action was run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerActionState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    pub cast: CastDraft,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CastDraft {
+    pub text: String,
+    #[serde(default)]
+    pub embeds: Vec<String>,
+    /// Hash of the cast being replied to, or URL of the channel being cast in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+}
+
+impl ComposerActionState {
+    /// The state as clients sign it: URI component encoded JSON.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_string(self).expect("Serialize Composer State Error");
+        utf8_percent_encode(&json, URI_COMPONENT).to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatedComposerAction {
+    pub fid: u64,
+    pub url: String,
+    pub state: ComposerActionState,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub network: i32,
+    pub message_hash: String,
+    pub signer: String,
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Serialize)]
 pub enum ErrorCode {
     InvalidInputText,
     InvalidURL,
@@ -8,16 +10,39 @@ pub enum ErrorCode {
     MissingTitle,
     InvalidButtonSequence,
     InvalidAspectRadio,
+    InvalidActionPayload,
+    InvalidMessageBytes,
+    InvalidMessageType,
+    InvalidMessageHash,
+    InvalidSignature,
+    UnknownPage,
+    UnhandledButton,
+    InvalidMintTarget,
+    UnsupportedChain,
+    MissingContract,
+    UnsupportedTokenStandard,
+    FailedToQueryChain,
+    FailedToRenderImage,
+    InvalidImageUrl,
+    StaleAction,
+    ReplayedAction,
+    FailedToCheckReplay,
+    UrlMismatch,
+    InvalidCastAction,
+    ExpiredAction,
+    InvalidComposerAction,
+    UnregisteredSigner,
+    FailedToCheckSigner,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct Error {
     pub description: String,
     pub code: ErrorCode,
     pub key: Option<String>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct FrameErrors {
     pub errors: Vec<Error>,
 }
@@ -62,7 +87,30 @@ impl std::fmt::Display for FrameErrors {
                     ErrorCode::FailedToFetchFrameHTML => "Failed to fetch frame HTML.",
                     ErrorCode::MissingTitle => "Please ensure a <title> tag is present within the HTML metadata for proper frame functionality.",
                     ErrorCode::InvalidButtonSequence => "Button indices are not in a consecutive sequence starting from 1.",
-                    ErrorCode::InvalidAspectRadio => "Invalid Aspect Radio. (Must be either 1.91:1 or 1:1)"
+                    ErrorCode::InvalidAspectRadio => "Invalid Aspect Radio. (Must be either 1.91:1 or 1:1)",
+                    ErrorCode::InvalidActionPayload => "The frame action payload is malformed.",
+                    ErrorCode::InvalidMessageBytes => "Failed to decode the frame action message bytes.",
+                    ErrorCode::InvalidMessageType => "The signed message is not a frame action.",
+                    ErrorCode::InvalidMessageHash => "The message hash does not match the message data.",
+                    ErrorCode::InvalidSignature => "The message signature is invalid.",
+                    ErrorCode::UnknownPage => "The requested frame page is not registered.",
+                    ErrorCode::UnhandledButton => "No handler is registered for the pressed button.",
+                    ErrorCode::InvalidMintTarget => "The mint target is not a valid CAIP-10 address.",
+                    ErrorCode::UnsupportedChain => "The chain is not supported.",
+                    ErrorCode::MissingContract => "No contract is deployed at the address.",
+                    ErrorCode::UnsupportedTokenStandard => "The contract does not support ERC-721 or ERC-1155.",
+                    ErrorCode::FailedToQueryChain => "Failed to query the chain.",
+                    ErrorCode::FailedToRenderImage => "Failed to render the frame image.",
+                    ErrorCode::InvalidImageUrl => "The image URL is malformed or has expired.",
+                    ErrorCode::StaleAction => "The frame action timestamp is outside the accepted window.",
+                    ErrorCode::ReplayedAction => "The frame action has already been handled.",
+                    ErrorCode::FailedToCheckReplay => "Failed to check whether the frame action was replayed.",
+                    ErrorCode::UrlMismatch => "The signed URL does not match the endpoint handling the action.",
+                    ErrorCode::InvalidCastAction => "The cast action metadata or response is invalid.",
+                    ErrorCode::ExpiredAction => "The frame action signature has expired.",
+                    ErrorCode::InvalidComposerAction => "The composer action metadata or form response is invalid.",
+                    ErrorCode::UnregisteredSigner => "The message signer is not allowed to act for the fid or Lens profile.",
+                    ErrorCode::FailedToCheckSigner => "Failed to check the signers registered to the fid or Lens profile.",
                 },
                 error.description,
                 error.key.clone().unwrap_or("".to_string())
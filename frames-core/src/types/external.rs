@@ -1,3 +1,4 @@
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -35,4 +36,57 @@ pub struct PayloadUntrustedData {
     pub cast_id: PayloadCastId,
     #[serde(rename = "inputText")]
     pub input_text: Option<String>,
+    pub state: Option<String>,
+    #[serde(rename = "transactionId")]
+    pub transaction_id: Option<String>,
+    pub address: Option<String>,
+}
+
+/// Body of the POST a client sends when a cast action is invoked. The signed message is a frame
+/// action whose `castId` is the cast the action was invoked on.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CastActionPayload {
+    #[serde(rename = "trustedData")]
+    pub trusted_data: PayloadTrustedData,
+    #[serde(rename = "untrustedData")]
+    pub untrusted_data: CastActionUntrustedData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CastActionUntrustedData {
+    pub fid: i32,
+    pub url: String,
+    #[serde(rename = "messageHash")]
+    pub message_hash: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: chrono::DateTime<Utc>,
+    pub network: i32,
+    #[serde(rename = "buttonIndex")]
+    pub button_index: i32,
+    #[serde(rename = "castId")]
+    pub cast_id: PayloadCastId,
+}
+
+/// Body of the POST a client sends when a composer action is run. `state` carries the draft
+/// being composed, see [`ComposerActionState`](crate::types::composer_action::ComposerActionState).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComposerActionPayload {
+    #[serde(rename = "trustedData")]
+    pub trusted_data: PayloadTrustedData,
+    #[serde(rename = "untrustedData")]
+    pub untrusted_data: ComposerActionUntrustedData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComposerActionUntrustedData {
+    pub fid: i32,
+    pub url: String,
+    #[serde(rename = "messageHash")]
+    pub message_hash: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: chrono::DateTime<Utc>,
+    pub network: i32,
+    #[serde(rename = "buttonIndex")]
+    pub button_index: i32,
+    pub state: String,
 }
@@ -0,0 +1,19 @@
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FnameTransferResponse {
+    pub transfer: FnameTransfer,
+}
+
+/// Latest transfer of an fname, as returned by the fname registry server. `to` is the fid the
+/// name belongs to, or 0 once the name is unregistered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FnameTransfer {
+    pub id: u64,
+    pub timestamp: u64,
+    pub username: String,
+    pub owner: Address,
+    pub from: u64,
+    pub to: u64,
+}
@@ -1,16 +1,63 @@
+use serde::{Deserialize, Serialize};
+
 use crate::types::{
     button::FrameButton,
     image::{AspectRatio, FrameImage},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Frame {
     pub title: String,
     pub version: String,
     pub image: FrameImage,
     pub post_url: Option<String>,
+    #[serde(default)]
     pub buttons: Vec<FrameButton>,
     pub input_text: Option<String>,
+    /// Open Frames metadata, emitted as `of:` tags next to the `fc:` tags when present.
+    #[serde(default)]
+    pub open_frames: Option<OpenFrames>,
+}
+
+/// Options of [`Frame::validate_with`], the defaults follow what clients accept.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ValidationOptions {
+    /// Accepts `localhost` and IPv4 URLs, which clients cannot load, in tools debugging frames
+    /// served locally.
+    pub allow_local_urls: bool,
+}
+
+impl ValidationOptions {
+    pub fn local() -> Self {
+        ValidationOptions { allow_local_urls: true }
+    }
+}
+
+pub const FARCASTER_PROTOCOL: &str = "farcaster";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpenFrames {
+    pub version: String,
+    #[serde(default)]
+    pub accepts: Vec<AcceptedProtocol>,
+}
+
+/// A protocol named by an `of:accepts:<id>` tag and the minimum version it must support.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcceptedProtocol {
+    pub id: String,
+    pub version: String,
+}
+
+impl OpenFrames {
+    pub fn new(version: &str) -> Self {
+        OpenFrames { version: version.to_string(), accepts: Vec::new() }
+    }
+
+    pub fn accept(mut self, id: &str, version: &str) -> Self {
+        self.accepts.push(AcceptedProtocol { id: id.to_string(), version: version.to_string() });
+        self
+    }
 }
 
 impl Frame {
@@ -22,8 +69,27 @@ impl Frame {
             post_url: None,
             buttons: Vec::new(),
             input_text: None,
+            open_frames: None,
         }
     }
+
+    /// Protocols whose clients can interact with the frame. The `fc:` tags imply Farcaster.
+    pub fn protocols(&self) -> Vec<&str> {
+        let mut protocols = Vec::new();
+        if !self.version.is_empty() {
+            protocols.push(FARCASTER_PROTOCOL);
+        }
+        for protocol in self.open_frames.iter().flat_map(|open_frames| &open_frames.accepts) {
+            if !protocols.contains(&protocol.id.as_str()) {
+                protocols.push(&protocol.id);
+            }
+        }
+        protocols
+    }
+
+    pub fn accepts(&self, protocol: &str) -> bool {
+        self.protocols().contains(&protocol)
+    }
 }
 
 impl Default for Frame {
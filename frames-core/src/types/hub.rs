@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubMessagesResponse {
+    pub messages: Vec<HubMessage>,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubMessage {
+    pub data: HubMessageData,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubMessageData {
+    #[serde(rename = "type")]
+    pub message_type: String,
+    pub fid: u64,
+    pub timestamp: u64,
+    #[serde(alias = "verificationAddEthAddressBody")]
+    pub verification_add_address_body: Option<VerificationAddAddressBody>,
+    pub user_data_body: Option<UserDataBody>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationAddAddressBody {
+    pub address: String,
+    #[serde(default)]
+    pub protocol: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserDataBody {
+    /// `USER_DATA_TYPE_PFP`, `USER_DATA_TYPE_DISPLAY`, `USER_DATA_TYPE_BIO`,
+    /// `USER_DATA_TYPE_URL` or `USER_DATA_TYPE_USERNAME`.
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub value: String,
+}
+
+/// Event of a Farcaster contract indexed by hubs, e.g. a key added to the KeyRegistry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnChainEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub fid: u64,
+    #[serde(default)]
+    pub signer_event_body: Option<SignerEventBody>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerEventBody {
+    /// `0x` prefixed hex of the Ed25519 public key.
+    pub key: String,
+    /// `SIGNER_EVENT_TYPE_ADD`, `SIGNER_EVENT_TYPE_REMOVE` or `SIGNER_EVENT_TYPE_ADMIN_RESET`.
+    pub event_type: String,
+}
+
+/// Error body returned by hubs, e.g. `{"errCode": "not_found", "message": "…"}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubError {
+    pub err_code: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl std::fmt::Display for HubError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.err_code, self.message)
+    }
+}
+
+impl std::error::Error for HubError {}
@@ -0,0 +1,23 @@
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FarcasterIdentity {
+    pub fid: u64,
+    pub custody_address: Option<Address>,
+    pub verified_addresses: Vec<Address>,
+}
+
+impl FarcasterIdentity {
+    /// Custody address followed by the verified addresses, without duplicates.
+    pub fn addresses(&self) -> Vec<Address> {
+        let mut addresses: Vec<Address> = self.custody_address.into_iter().collect();
+        for address in &self.verified_addresses {
+            if !addresses.contains(address) {
+                addresses.push(*address);
+            }
+        }
+        addresses
+    }
+}
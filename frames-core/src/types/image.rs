@@ -1,10 +1,17 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum AspectRatio {
+    #[default]
+    #[serde(rename = "none")]
     None,
+    #[serde(rename = "1:1")]
     OneToOne,
+    #[serde(rename = "1.91:1")]
     OnePointNineToOne,
+    #[serde(rename = "error")]
     Error,
 }
 
@@ -13,14 +20,15 @@ impl fmt::Display for AspectRatio {
         match self {
             AspectRatio::None => write!(f, "None"),
             AspectRatio::OneToOne => write!(f, "1:1"),
-            AspectRatio::OnePointNineToOne => write!(f, "1.9:1"),
+            AspectRatio::OnePointNineToOne => write!(f, "1.91:1"),
             AspectRatio::Error => write!(f, "Error"),
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameImage {
     pub url: String,
+    #[serde(default)]
     pub aspect_ratio: AspectRatio,
 }
@@ -0,0 +1,98 @@
+//! Open Frames action payloads sent by Lens clients, signed as EIP-712 `FrameData`.
+
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::types::external::PayloadTrustedData;
+
+pub const LENS_PROTOCOL: &str = "lens";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LensFrameActionPayload {
+    /// `lens@<version>`.
+    pub client_protocol: String,
+    /// `messageBytes` holds the `0x` prefixed signature of the typed data.
+    pub trusted_data: PayloadTrustedData,
+    pub untrusted_data: LensUntrustedData,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LensUntrustedData {
+    pub spec_version: String,
+    /// `0x` prefixed hex profile id.
+    pub profile_id: String,
+    /// `<profile id>-<publication id>` of the publication embedding the frame.
+    pub pub_id: String,
+    pub url: String,
+    pub button_index: u32,
+    /// Not part of the signed typed data, so it cannot be trusted.
+    pub unix_timestamp: i64,
+    /// Unix timestamp after which the signature is no longer valid.
+    pub deadline: Option<i64>,
+    pub input_text: Option<String>,
+    pub state: Option<String>,
+    /// Transaction hash of a `tx` button.
+    pub action_response: Option<String>,
+    pub identity_token: Option<String>,
+}
+
+/// EIP-712 domain of the Lens `FrameVerifier` deployment the client signs for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LensDomain {
+    pub chain_id: u64,
+    pub verifying_contract: Address,
+}
+
+impl LensDomain {
+    pub fn new(chain_id: u64, verifying_contract: Address) -> Self {
+        LensDomain { chain_id, verifying_contract }
+    }
+}
+
+impl LensUntrustedData {
+    /// EIP-712 typed data the client signs for these fields.
+    pub fn typed_data(&self, domain: &LensDomain) -> Value {
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" },
+                ],
+                "FrameData": [
+                    { "name": "specVersion", "type": "string" },
+                    { "name": "url", "type": "string" },
+                    { "name": "buttonIndex", "type": "uint256" },
+                    { "name": "profileId", "type": "uint256" },
+                    { "name": "pubId", "type": "string" },
+                    { "name": "inputText", "type": "string" },
+                    { "name": "state", "type": "string" },
+                    { "name": "actionResponse", "type": "string" },
+                    { "name": "deadline", "type": "uint256" },
+                ],
+            },
+            "primaryType": "FrameData",
+            "domain": {
+                "name": "FrameVerifier",
+                "version": "1",
+                "chainId": domain.chain_id,
+                "verifyingContract": format!("{:?}", domain.verifying_contract),
+            },
+            "message": {
+                "specVersion": self.spec_version,
+                "url": self.url,
+                "buttonIndex": self.button_index,
+                "profileId": self.profile_id,
+                "pubId": self.pub_id,
+                "inputText": self.input_text.clone().unwrap_or_default(),
+                "state": self.state.clone().unwrap_or_default(),
+                "actionResponse": self.action_response.clone().unwrap_or_default(),
+                "deadline": self.deadline.unwrap_or_default(),
+            },
+        })
+    }
+}
@@ -0,0 +1,111 @@
+//! Subset of the Farcaster protobuf schema needed to decode signed frame action messages.
+
+use prost::{Enumeration, Message as ProstMessage, Oneof};
+
+/// Farcaster epoch (2021-01-01T00:00:00Z) in milliseconds since the unix epoch.
+pub const FARCASTER_EPOCH: i64 = 1_609_459_200_000;
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct Message {
+    #[prost(message, optional, tag = "1")]
+    pub data: Option<MessageData>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash: Vec<u8>,
+    #[prost(enumeration = "HashScheme", tag = "3")]
+    pub hash_scheme: i32,
+    #[prost(bytes = "vec", tag = "4")]
+    pub signature: Vec<u8>,
+    #[prost(enumeration = "SignatureScheme", tag = "5")]
+    pub signature_scheme: i32,
+    #[prost(bytes = "vec", tag = "6")]
+    pub signer: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "7")]
+    pub data_bytes: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct MessageData {
+    #[prost(enumeration = "MessageType", tag = "1")]
+    pub r#type: i32,
+    #[prost(uint64, tag = "2")]
+    pub fid: u64,
+    #[prost(uint32, tag = "3")]
+    pub timestamp: u32,
+    #[prost(enumeration = "FarcasterNetwork", tag = "4")]
+    pub network: i32,
+    #[prost(oneof = "MessageBody", tags = "16")]
+    pub body: Option<MessageBody>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum MessageBody {
+    #[prost(message, tag = "16")]
+    FrameActionBody(FrameActionBody),
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct CastId {
+    #[prost(uint64, tag = "1")]
+    pub fid: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct FrameActionBody {
+    #[prost(bytes = "vec", tag = "1")]
+    pub url: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub button_index: u32,
+    #[prost(message, optional, tag = "3")]
+    pub cast_id: Option<CastId>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub input_text: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub state: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub transaction_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub address: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum MessageType {
+    None = 0,
+    CastAdd = 1,
+    CastRemove = 2,
+    ReactionAdd = 3,
+    ReactionRemove = 4,
+    LinkAdd = 5,
+    LinkRemove = 6,
+    VerificationAddEthAddress = 7,
+    VerificationRemove = 8,
+    UserDataAdd = 11,
+    UsernameProof = 12,
+    FrameAction = 13,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum HashScheme {
+    None = 0,
+    Blake3 = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum SignatureScheme {
+    None = 0,
+    Ed25519 = 1,
+    Eip712 = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum FarcasterNetwork {
+    None = 0,
+    Mainnet = 1,
+    Testnet = 2,
+    Devnet = 3,
+}
@@ -1,5 +1,20 @@
+pub mod action;
 pub mod button;
+pub mod caip;
+pub mod cast_action;
+pub mod composer_action;
 pub mod errors;
 pub mod external;
+pub mod fname;
 pub mod frame;
+pub mod hub;
+pub mod identity;
 pub mod image;
+#[cfg(feature = "lens")]
+pub mod lens;
+pub mod message;
+pub mod profile;
+pub mod token;
+pub mod transaction;
+#[cfg(feature = "xmtp")]
+pub mod xmtp;
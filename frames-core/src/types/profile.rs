@@ -0,0 +1,24 @@
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    pub fid: u64,
+    pub username: Option<String>,
+    pub display_name: Option<String>,
+    pub pfp_url: Option<String>,
+    pub bio: Option<String>,
+    pub url: Option<String>,
+    pub verified_addresses: Vec<Address>,
+}
+
+impl UserProfile {
+    /// Display name, falling back to the username and then to the fid.
+    pub fn name(&self) -> String {
+        self.display_name
+            .clone()
+            .or_else(|| self.username.clone())
+            .unwrap_or_else(|| format!("!{}", self.fid))
+    }
+}
@@ -0,0 +1,53 @@
+use ethers::types::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStandard {
+    Erc20,
+    Erc721,
+    Erc1155 { token_id: U256 },
+}
+
+/// Minimum balance of a token that the addresses of an fid must hold, summed across addresses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenRequirement {
+    pub contract: Address,
+    pub standard: TokenStandard,
+    pub min_balance: U256,
+}
+
+impl TokenRequirement {
+    pub fn erc20(contract: Address, min_balance: U256) -> Self {
+        TokenRequirement { contract, standard: TokenStandard::Erc20, min_balance }
+    }
+
+    pub fn erc721(contract: Address) -> Self {
+        TokenRequirement { contract, standard: TokenStandard::Erc721, min_balance: U256::one() }
+    }
+
+    pub fn erc1155(contract: Address, token_id: U256, min_balance: U256) -> Self {
+        TokenRequirement { contract, standard: TokenStandard::Erc1155 { token_id }, min_balance }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenGateResult {
+    pub fid: u64,
+    pub balances: Vec<(Address, U256)>,
+    pub passed: bool,
+}
+
+impl TokenGateResult {
+    pub fn new(fid: u64, balances: Vec<(Address, U256)>, requirement: &TokenRequirement) -> Self {
+        let mut result = TokenGateResult { fid, balances, passed: false };
+        result.passed = result.total_balance() >= requirement.min_balance;
+        result
+    }
+
+    pub fn total_balance(&self) -> U256 {
+        self.balances
+            .iter()
+            .fold(U256::zero(), |total, (_, balance)| total.saturating_add(*balance))
+    }
+}
@@ -0,0 +1,63 @@
+use ethers::types::{Address, Bytes, TransactionRequest, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::caip::eip155_chain_id;
+
+/// The only method of frame transactions, answered by sending `params` from the user's wallet.
+pub const SEND_TRANSACTION_METHOD: &str = "eth_sendTransaction";
+
+/// Transaction returned by the `target` of a `tx` button, to be sent by the user's wallet.
+///
+/// ```json
+/// {
+///   "chainId": "eip155:10",
+///   "method": "eth_sendTransaction",
+///   "params": { "abi": [], "to": "0x…", "data": "0x…", "value": "1000000000000000" }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameTransaction {
+    pub chain_id: String,
+    pub method: String,
+    pub params: TransactionParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionParams {
+    #[serde(default)]
+    pub abi: Vec<Value>,
+    pub to: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Bytes>,
+}
+
+impl FrameTransaction {
+    pub fn chain_id(&self) -> Result<u64, String> {
+        eip155_chain_id(&self.chain_id)
+    }
+
+    /// Request sending the transaction from `from`. `value` is a decimal amount of wei.
+    pub fn request(&self, from: Address) -> Result<TransactionRequest, String> {
+        if self.method != SEND_TRANSACTION_METHOD {
+            return Err(format!(
+                "{} is not a transaction method, expected {}.",
+                self.method, SEND_TRANSACTION_METHOD
+            ));
+        }
+
+        let mut request = TransactionRequest::new().from(from).to(self.params.to);
+        if let Some(value) = &self.params.value {
+            let value = U256::from_dec_str(value)
+                .map_err(|_| format!("{} is not a valid amount of wei.", value))?;
+            request = request.value(value);
+        }
+        if let Some(data) = &self.params.data {
+            request = request.data(data.clone());
+        }
+        Ok(request)
+    }
+}
@@ -0,0 +1,139 @@
+//! Open Frames action payloads sent by XMTP clients, and the subset of the XMTP protobuf schema
+//! needed to verify them.
+
+use chrono::{DateTime, Utc};
+use prost::{Message as ProstMessage, Oneof};
+use serde::{Deserialize, Serialize};
+
+use crate::types::external::PayloadTrustedData;
+
+pub const XMTP_PROTOCOL: &str = "xmtp";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmtpFrameActionPayload {
+    /// `xmtp@<version>`.
+    pub client_protocol: String,
+    pub trusted_data: PayloadTrustedData,
+    pub untrusted_data: XmtpUntrustedData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmtpUntrustedData {
+    pub wallet_address: String,
+    pub url: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    pub button_index: i32,
+    pub input_text: Option<String>,
+    pub state: Option<String>,
+    pub transaction_id: Option<String>,
+    pub address: Option<String>,
+    pub opaque_conversation_identifier: String,
+    pub unix_timestamp: u32,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct FrameAction {
+    #[prost(message, optional, tag = "1")]
+    pub signature: Option<Signature>,
+    #[prost(message, optional, tag = "2")]
+    pub signed_public_key_bundle: Option<SignedPublicKeyBundle>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub action_body: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct FrameActionBody {
+    #[prost(string, tag = "1")]
+    pub frame_url: String,
+    #[prost(int32, tag = "2")]
+    pub button_index: i32,
+    /// Milliseconds since the unix epoch.
+    #[prost(uint64, tag = "3")]
+    pub timestamp: u64,
+    #[prost(string, tag = "4")]
+    pub opaque_conversation_identifier: String,
+    #[prost(uint32, tag = "5")]
+    pub unix_timestamp: u32,
+    #[prost(string, tag = "6")]
+    pub input_text: String,
+    #[prost(string, tag = "7")]
+    pub state: String,
+    #[prost(string, tag = "8")]
+    pub address: String,
+    #[prost(string, tag = "9")]
+    pub transaction_id: String,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct Signature {
+    #[prost(oneof = "SignatureUnion", tags = "1, 2")]
+    pub union: Option<SignatureUnion>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum SignatureUnion {
+    /// Signed by an XMTP key over a sha256 digest.
+    #[prost(message, tag = "1")]
+    EcdsaCompact(RecoverableSignature),
+    /// Signed by a wallet with `personal_sign`.
+    #[prost(message, tag = "2")]
+    WalletEcdsaCompact(RecoverableSignature),
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct RecoverableSignature {
+    /// `r` and `s`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub bytes: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub recovery: u32,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct SignedPublicKeyBundle {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: Option<SignedPublicKey>,
+    #[prost(message, optional, tag = "2")]
+    pub pre_key: Option<SignedPublicKey>,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct SignedPublicKey {
+    /// A serialized [`UnsignedPublicKey`].
+    #[prost(bytes = "vec", tag = "1")]
+    pub key_bytes: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub signature: Option<Signature>,
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct UnsignedPublicKey {
+    #[prost(uint64, tag = "1")]
+    pub created_ns: u64,
+    #[prost(oneof = "PublicKeyUnion", tags = "3")]
+    pub union: Option<PublicKeyUnion>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum PublicKeyUnion {
+    /// 65 byte uncompressed secp256k1 point.
+    #[prost(message, tag = "3")]
+    Secp256k1Uncompressed(Secp256k1Uncompressed),
+}
+
+#[derive(Clone, PartialEq, ProstMessage)]
+pub struct Secp256k1Uncompressed {
+    #[prost(bytes = "vec", tag = "1")]
+    pub bytes: Vec<u8>,
+}
+
+/// Text a wallet signs to register an XMTP identity key.
+pub fn identity_signature_text(key_bytes: &[u8]) -> String {
+    format!(
+        "XMTP : Create Identity\n{}\n\nFor more info: https://xmtp.org/signatures/",
+        hex::encode(key_bytes)
+    )
+}
@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use ed25519_dalek::{Signature, VerifyingKey};
+use prost::Message as ProstMessage;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+#[cfg(feature = "xmtp")]
+use crate::types::xmtp::XmtpFrameActionPayload;
+#[cfg(feature = "lens")]
+use crate::{
+    provider::lens::LensProfileProvider,
+    types::lens::{LensDomain, LensFrameActionPayload},
+};
+use crate::{
+    provider::signer::SignerProvider,
+    types::{
+        action::{ActionCastId, FrameAction, ValidatedFrameAction},
+        errors::{Error, ErrorCode, FrameErrors},
+        external::FrameActionPayload,
+        message::{
+            HashScheme, Message, MessageBody, MessageType, SignatureScheme, FARCASTER_EPOCH,
+        },
+    },
+};
+
+/// Verifies action payloads of any supported protocol into a [`FrameAction`], dispatching on
+/// their Open Frames `clientProtocol`. Payloads without one are Farcaster payloads.
+#[derive(Clone)]
+pub struct FrameActionVerifier {
+    signers: Arc<dyn SignerProvider>,
+    #[cfg(feature = "lens")]
+    lens: Option<(LensDomain, Arc<dyn LensProfileProvider>)>,
+}
+
+impl FrameActionVerifier {
+    /// Checks the signers of Farcaster actions with `signers`, e.g. a
+    /// [`HubClient`](crate::provider::hub::HubClient).
+    pub fn new(signers: impl SignerProvider + 'static) -> Self {
+        Self::from_arc(Arc::new(signers))
+    }
+
+    pub fn from_arc(signers: Arc<dyn SignerProvider>) -> Self {
+        FrameActionVerifier {
+            signers,
+            #[cfg(feature = "lens")]
+            lens: None,
+        }
+    }
+
+    pub fn signers(&self) -> &dyn SignerProvider {
+        self.signers.as_ref()
+    }
+
+    /// Accepts Lens actions signed for `domain` by wallets `profiles` allows to act for the
+    /// profile, e.g. a [`LensHubProvider`](crate::provider::lens::LensHubProvider).
+    #[cfg(feature = "lens")]
+    pub fn with_lens(
+        mut self,
+        domain: LensDomain,
+        profiles: impl LensProfileProvider + 'static,
+    ) -> Self {
+        self.lens = Some((domain, Arc::new(profiles)));
+        self
+    }
+
+    pub async fn verify(&self, payload: &Value) -> Result<FrameAction, FrameErrors> {
+        let client_protocol =
+            payload.get("clientProtocol").and_then(Value::as_str).unwrap_or("farcaster");
+
+        match client_protocol.split('@').next().unwrap_or_default() {
+            "farcaster" => {
+                Ok(parse::<FrameActionPayload>(payload)?.verify(self.signers()).await?.into())
+            }
+            #[cfg(feature = "xmtp")]
+            "xmtp" => parse::<XmtpFrameActionPayload>(payload)?.verify(),
+            #[cfg(feature = "lens")]
+            "lens" => match &self.lens {
+                Some((domain, profiles)) => {
+                    parse::<LensFrameActionPayload>(payload)?
+                        .verify(domain, profiles.as_ref())
+                        .await
+                }
+                None => Err(action_error(
+                    ErrorCode::InvalidActionPayload,
+                    "Lens actions are not accepted without a Lens domain and profile provider.",
+                )),
+            },
+            _ => Err(action_error(
+                ErrorCode::InvalidActionPayload,
+                &format!("The client protocol {} is not supported.", client_protocol),
+            )),
+        }
+    }
+}
+
+fn parse<T: DeserializeOwned>(payload: &Value) -> Result<T, FrameErrors> {
+    serde_json::from_value(payload.clone())
+        .map_err(|e| action_error(ErrorCode::InvalidActionPayload, &e.to_string()))
+}
+
+impl FrameActionPayload {
+    pub async fn verify(
+        &self,
+        signers: &dyn SignerProvider,
+    ) -> Result<ValidatedFrameAction, FrameErrors> {
+        decode_message(&self.trusted_data.message_bytes)?.verify(signers).await
+    }
+}
+
+pub(crate) fn decode_message(message_bytes: &str) -> Result<Message, FrameErrors> {
+    let bytes = hex::decode(message_bytes.trim_start_matches("0x")).map_err(|_| {
+        action_error(ErrorCode::InvalidMessageBytes, "messageBytes is not valid hex.")
+    })?;
+    Message::decode(bytes.as_slice()).map_err(|_| {
+        action_error(
+            ErrorCode::InvalidMessageBytes,
+            "messageBytes is not a valid Farcaster message.",
+        )
+    })
+}
+
+impl Message {
+    /// Checks the message hash and signature, then asks `signers` whether the signer is a key of
+    /// the fid the message claims to be from.
+    pub async fn verify(
+        &self,
+        signers: &dyn SignerProvider,
+    ) -> Result<ValidatedFrameAction, FrameErrors> {
+        let action = self.verify_signature()?;
+
+        match signers.is_active_signer(action.fid, &self.signer).await {
+            Ok(true) => Ok(action),
+            Ok(false) => Err(action_error(
+                ErrorCode::UnregisteredSigner,
+                &format!("{} is not an active key of fid {}.", action.signer, action.fid),
+            )),
+            Err(e) => Err(action_error(ErrorCode::FailedToCheckSigner, &e.to_string())),
+        }
+    }
+
+    fn verify_signature(&self) -> Result<ValidatedFrameAction, FrameErrors> {
+        let data = match &self.data {
+            Some(data) => data,
+            None => {
+                return Err(action_error(
+                    ErrorCode::InvalidMessageBytes,
+                    "The message does not contain any data.",
+                ))
+            }
+        };
+
+        let body = match (data.r#type(), &data.body) {
+            (MessageType::FrameAction, Some(MessageBody::FrameActionBody(body))) => body,
+            _ => {
+                return Err(action_error(
+                    ErrorCode::InvalidMessageType,
+                    "Expected a MESSAGE_TYPE_FRAME_ACTION message.",
+                ))
+            }
+        };
+
+        // hubs hash the original serialized data when it is attached to the message
+        let data_bytes = self.data_bytes.clone().unwrap_or_else(|| data.encode_to_vec());
+        let hash = blake3::hash(&data_bytes);
+        if self.hash_scheme() != HashScheme::Blake3 || self.hash != hash.as_bytes()[..20] {
+            return Err(action_error(
+                ErrorCode::InvalidMessageHash,
+                "The message hash does not match the message data.",
+            ));
+        }
+
+        if self.signature_scheme() != SignatureScheme::Ed25519 || !self.has_valid_signature() {
+            return Err(action_error(
+                ErrorCode::InvalidSignature,
+                "The message signature is invalid.",
+            ));
+        }
+
+        let timestamp = Utc
+            .timestamp_millis_opt(FARCASTER_EPOCH + i64::from(data.timestamp) * 1000)
+            .single()
+            .unwrap_or_default();
+
+        Ok(ValidatedFrameAction {
+            fid: data.fid,
+            url: String::from_utf8_lossy(&body.url).to_string(),
+            button_index: body.button_index,
+            input_text: non_empty_string(&body.input_text),
+            state: non_empty_string(&body.state),
+            cast_id: body.cast_id.as_ref().map(|cast_id| ActionCastId {
+                fid: cast_id.fid,
+                hash: format!("0x{}", hex::encode(&cast_id.hash)),
+            }),
+            transaction_id: non_empty_hex(&body.transaction_id),
+            address: non_empty_hex(&body.address),
+            timestamp,
+            network: data.network,
+            message_hash: format!("0x{}", hex::encode(&self.hash)),
+            signer: format!("0x{}", hex::encode(&self.signer)),
+        })
+    }
+
+    fn has_valid_signature(&self) -> bool {
+        let key = match <[u8; 32]>::try_from(self.signer.as_slice()) {
+            Ok(bytes) => VerifyingKey::from_bytes(&bytes),
+            Err(_) => return false,
+        };
+        let signature = Signature::from_slice(&self.signature);
+
+        match (key, signature) {
+            (Ok(key), Ok(signature)) => key.verify_strict(&self.hash, &signature).is_ok(),
+            _ => false,
+        }
+    }
+}
+
+fn non_empty_string(bytes: &[u8]) -> Option<String> {
+    if bytes.is_empty() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(bytes).to_string())
+}
+
+fn non_empty_hex(bytes: &[u8]) -> Option<String> {
+    if bytes.is_empty() {
+        return None;
+    }
+    Some(format!("0x{}", hex::encode(bytes)))
+}
+
+pub(crate) fn action_error(code: ErrorCode, description: &str) -> FrameErrors {
+    let mut errors = FrameErrors::new();
+    errors.add_error(Error { description: description.to_string(), code, key: None });
+    errors
+}
@@ -4,7 +4,7 @@ use crate::types::{
 };
 
 impl FrameButton {
-    const VALID_ACTIONS: [&'static str; 4] = ["post_redirect", "post", "mint", "link"];
+    const VALID_ACTIONS: [&'static str; 5] = ["post_redirect", "post", "mint", "link", "tx"];
 
     pub fn validate(&self) -> Result<(), FrameErrors> {
         let mut errors = FrameErrors::new();
@@ -0,0 +1,138 @@
+use crate::{
+    provider::signer::SignerProvider,
+    types::{
+        cast_action::{ActionType, CastActionMetadata, CastActionResponse, ValidatedCastAction},
+        errors::{Error, ErrorCode, FrameErrors},
+        external::CastActionPayload,
+    },
+    validators::action::{action_error, decode_message},
+    URL_REGEX,
+};
+
+impl CastActionPayload {
+    pub async fn verify(
+        &self,
+        signers: &dyn SignerProvider,
+    ) -> Result<ValidatedCastAction, FrameErrors> {
+        let action = decode_message(&self.trusted_data.message_bytes)?.verify(signers).await?;
+        let cast_id = action.cast_id.ok_or_else(|| {
+            action_error(
+                ErrorCode::InvalidActionPayload,
+                "The cast action message does not reference a cast.",
+            )
+        })?;
+
+        Ok(ValidatedCastAction {
+            fid: action.fid,
+            url: action.url,
+            cast_id,
+            timestamp: action.timestamp,
+            network: action.network,
+            message_hash: action.message_hash,
+            signer: action.signer,
+        })
+    }
+}
+
+impl CastActionMetadata {
+    pub fn validate(&self) -> Result<(), FrameErrors> {
+        let mut errors = FrameErrors::new();
+
+        if self.action.action_type != ActionType::Post {
+            errors.add_error(cast_action_error(
+                "Cast actions must have a post action.".to_string(),
+                "action.type",
+            ));
+        }
+
+        validate_listing(&mut errors, cast_action_error, &self.name, &self.icon, &self.description);
+        validate_url(&mut errors, self.about_url.as_deref(), "aboutUrl");
+        validate_url(&mut errors, self.action.post_url.as_deref(), "action.postUrl");
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+const MAX_NAME_LENGTH: usize = 20;
+const MAX_DESCRIPTION_LENGTH: usize = 80;
+
+/// Checks the fields clients show when listing an action, reporting them with `error`.
+pub(crate) fn validate_listing(
+    errors: &mut FrameErrors,
+    error: fn(String, &str) -> Error,
+    name: &str,
+    icon: &str,
+    description: &str,
+) {
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        errors.add_error(error(
+            format!("The name must be 1 to {} characters.", MAX_NAME_LENGTH),
+            "name",
+        ));
+    }
+
+    // octicon names, e.g. `thumbsup` or `arrow-up-right`
+    let valid_icon = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-';
+    if icon.is_empty() || !icon.chars().all(valid_icon) {
+        errors.add_error(error(format!("{} is not an Octicon name.", icon), "icon"));
+    }
+
+    if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+        errors.add_error(error(
+            format!("The description must be at most {} characters.", MAX_DESCRIPTION_LENGTH),
+            "description",
+        ));
+    }
+}
+
+impl CastActionResponse {
+    const MAX_MESSAGE_LENGTH: usize = 80;
+
+    pub fn validate(&self) -> Result<(), FrameErrors> {
+        let mut errors = FrameErrors::new();
+
+        match self {
+            CastActionResponse::Message { message, link } => {
+                if message.is_empty() || message.chars().count() > Self::MAX_MESSAGE_LENGTH {
+                    errors.add_error(cast_action_error(
+                        format!(
+                            "The message must be 1 to {} characters.",
+                            Self::MAX_MESSAGE_LENGTH
+                        ),
+                        "message",
+                    ));
+                }
+                validate_url(&mut errors, link.as_deref(), "link");
+            }
+            CastActionResponse::Frame { frame_url } => {
+                validate_url(&mut errors, Some(frame_url), "frameUrl");
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn validate_url(errors: &mut FrameErrors, url: Option<&str>, key: &str) {
+    if let Some(url) = url {
+        if !URL_REGEX.is_match(url) {
+            errors.add_error(Error {
+                description: "The URL provided is invalid.".to_string(),
+                code: ErrorCode::InvalidURL,
+                key: Some(key.to_string()),
+            });
+        }
+    }
+}
+
+fn cast_action_error(description: String, key: &str) -> Error {
+    Error { description, code: ErrorCode::InvalidCastAction, key: Some(key.to_string()) }
+}
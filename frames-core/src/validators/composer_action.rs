@@ -0,0 +1,119 @@
+use percent_encoding::percent_decode_str;
+
+use crate::{
+    provider::signer::SignerProvider,
+    types::{
+        cast_action::ActionType,
+        composer_action::{
+            ComposerActionMetadata, ComposerActionResponse, ComposerActionState,
+            ValidatedComposerAction,
+        },
+        errors::{Error, ErrorCode, FrameErrors},
+        external::ComposerActionPayload,
+    },
+    validators::{
+        action::{action_error, decode_message},
+        cast_action::{validate_listing, validate_url},
+    },
+};
+
+impl ComposerActionPayload {
+    /// Verifies the signed message, reading the draft from its signed state rather than from
+    /// `untrustedData`.
+    pub async fn verify(
+        &self,
+        signers: &dyn SignerProvider,
+    ) -> Result<ValidatedComposerAction, FrameErrors> {
+        let action = decode_message(&self.trusted_data.message_bytes)?.verify(signers).await?;
+        let state = ComposerActionState::decode(action.state.as_deref().unwrap_or_default())?;
+
+        Ok(ValidatedComposerAction {
+            fid: action.fid,
+            url: action.url,
+            state,
+            timestamp: action.timestamp,
+            network: action.network,
+            message_hash: action.message_hash,
+            signer: action.signer,
+        })
+    }
+}
+
+impl ComposerActionState {
+    /// Parses state encoded by [`ComposerActionState::encode`].
+    pub fn decode(state: &str) -> Result<Self, FrameErrors> {
+        let json = percent_decode_str(state).decode_utf8().map_err(|_| {
+            action_error(ErrorCode::InvalidActionPayload, "The composer state is not valid UTF-8.")
+        })?;
+        serde_json::from_str(&json).map_err(|e| {
+            action_error(
+                ErrorCode::InvalidActionPayload,
+                &format!("The composer state is malformed: {}", e),
+            )
+        })
+    }
+}
+
+impl ComposerActionMetadata {
+    pub fn validate(&self) -> Result<(), FrameErrors> {
+        let mut errors = FrameErrors::new();
+
+        if self.action_type != ActionType::Composer {
+            errors.add_error(composer_action_error(
+                "Composer action metadata must have type composer.".to_string(),
+                "type",
+            ));
+        }
+        if self.action.action_type != ActionType::Post {
+            errors.add_error(composer_action_error(
+                "Composer actions must have a post action.".to_string(),
+                "action.type",
+            ));
+        }
+
+        validate_listing(
+            &mut errors,
+            composer_action_error,
+            &self.name,
+            &self.icon,
+            &self.description,
+        );
+        validate_url(&mut errors, self.about_url.as_deref(), "aboutUrl");
+        validate_url(&mut errors, self.image_url.as_deref(), "imageUrl");
+        validate_url(&mut errors, self.action.post_url.as_deref(), "action.postUrl");
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+impl ComposerActionResponse {
+    pub fn validate(&self) -> Result<(), FrameErrors> {
+        let mut errors = FrameErrors::new();
+
+        match self {
+            ComposerActionResponse::Form { title, url } => {
+                if title.is_empty() {
+                    errors.add_error(composer_action_error(
+                        "The form title is empty.".to_string(),
+                        "title",
+                    ));
+                }
+                validate_url(&mut errors, Some(url), "url");
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+fn composer_action_error(description: String, key: &str) -> Error {
+    Error { description, code: ErrorCode::InvalidComposerAction, key: Some(key.to_string()) }
+}
@@ -1,20 +1,38 @@
 use std::collections::HashMap;
 
+use regex::Regex;
 use scraper::{Html, Selector};
 
-use crate::types::{
-    button::FrameButton,
-    errors::{Error, ErrorCode, FrameErrors},
-    frame::Frame,
-    image::AspectRatio,
+use crate::{
+    types::{
+        button::FrameButton,
+        errors::{Error, ErrorCode, FrameErrors},
+        frame::{AcceptedProtocol, Frame, ValidationOptions},
+        image::AspectRatio,
+    },
+    DEV_URL_REGEX, URL_REGEX,
 };
 
+impl ValidationOptions {
+    pub(crate) fn url_regex(&self) -> &'static Regex {
+        if self.allow_local_urls {
+            &DEV_URL_REGEX
+        } else {
+            &URL_REGEX
+        }
+    }
+}
+
 impl Frame {
     pub fn validate(&self) -> Result<(), FrameErrors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    pub fn validate_with(&self, options: &ValidationOptions) -> Result<(), FrameErrors> {
         let mut errors = FrameErrors::new();
 
         if let Some(input_texts) = &self.input_text {
-            let byte_len = input_texts.as_bytes().len();
+            let byte_len = input_texts.len();
             if byte_len > 32 {
                 errors.add_error(Error {
                     code: ErrorCode::InvalidInputText,
@@ -26,7 +44,7 @@ impl Frame {
 
         // TODO: validate post url 256-byte and valid url
 
-        match self.image.validate() {
+        match self.image.validate_with(options) {
             Ok(_) => (),
             Err(e) => errors.add_errors(e.errors),
         }
@@ -46,12 +64,20 @@ impl Frame {
     }
 
     pub fn from_url(&mut self, url: &str) -> Result<&mut Self, FrameErrors> {
+        self.from_url_with(url, &ValidationOptions::default())
+    }
+
+    pub fn from_url_with(
+        &mut self,
+        url: &str,
+        options: &ValidationOptions,
+    ) -> Result<&mut Self, FrameErrors> {
         let response = reqwest::blocking::get(url);
         match response {
             Ok(body) => {
                 let text = body.text();
                 match text {
-                    Ok(html) => self.from_html(&html),
+                    Ok(html) => self.from_html_with(&html, options),
                     Err(_) => {
                         let mut errors = FrameErrors::new();
                         let error = Error {
@@ -79,6 +105,14 @@ impl Frame {
     }
 
     pub fn from_html(&mut self, html: &str) -> Result<&mut Self, FrameErrors> {
+        self.from_html_with(html, &ValidationOptions::default())
+    }
+
+    pub fn from_html_with(
+        &mut self,
+        html: &str,
+        options: &ValidationOptions,
+    ) -> Result<&mut Self, FrameErrors> {
         let document = Html::parse_document(html);
         let mut errors = FrameErrors::new();
 
@@ -97,54 +131,39 @@ impl Frame {
 
         let selector = Selector::parse("meta").unwrap();
         let mut temp_buttons: HashMap<usize, FrameButton> = HashMap::new();
-        for element in document.select(&selector) {
-            if let Some(name) = element.value().attr("name") {
-                if let Some(_content) = element.value().attr("content") {
-                    let content = _content.to_string();
-                    match name {
-                        "fc:frame" => self.version = content,
-                        "fc:frame:image" => self.image.url = content,
-                        "fc:frame:image:aspect_ratio" => {
-                            self.image.aspect_ratio = match _content {
-                                "1.91:1" => AspectRatio::OnePointNineToOne,
-                                "1:1" => AspectRatio::OneToOne,
-                                _ => AspectRatio::Error,
-                            }
-                        }
-                        "fc:frame:post_url" => self.post_url = Some(content),
-                        "fc:frame:input:text" => self.input_text = Some(content),
-                        name if name.starts_with("fc:frame:button:") => {
-                            let parts: Vec<&str> = name.split(':').collect();
-                            if let Ok(idx) = parts[3].parse::<usize>() {
-                                match parts.get(4) {
-                                    Some(&"action") => {
-                                        if let Some(button) = temp_buttons.get_mut(&idx) {
-                                            button.action = Some(content);
-                                        } else {
-                                            let button = FrameButton {
-                                                id: idx,
-                                                label: content.clone(),
-                                                action: Some(content),
-                                                target: None,
-                                            };
-                                            temp_buttons.insert(idx, button);
-                                        }
-                                    }
-                                    _ => {
-                                        let button = FrameButton {
-                                            id: idx,
-                                            label: content,
-                                            action: Some("post".to_string()),
-                                            target: None,
-                                        };
-                                        temp_buttons.insert(idx, button);
-                                    }
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
+        let tags = document.select(&selector).filter_map(|element| {
+            let name = element.value().attr("name").or_else(|| element.value().attr("property"))?;
+            Some((name, element.value().attr("content")?))
+        });
+        // `fc:frame` tags take precedence over their Open Frames equivalents
+        let (open_frames, farcaster): (Vec<_>, Vec<_>) =
+            tags.partition(|(name, _)| name.starts_with("of:"));
+
+        for (name, content) in open_frames {
+            match name {
+                "of:version" => {
+                    self.open_frames.get_or_insert_with(Default::default).version =
+                        content.to_string()
                 }
+                name if name.starts_with("of:accepts:") => {
+                    self.open_frames.get_or_insert_with(Default::default).accepts.push(
+                        AcceptedProtocol {
+                            id: name["of:accepts:".len()..].to_string(),
+                            version: content.to_string(),
+                        },
+                    )
+                }
+                name => self.apply_tag(&name["of:".len()..], content, &mut temp_buttons),
+            }
+        }
+
+        for (name, content) in farcaster {
+            match name {
+                "fc:frame" => self.version = content.to_string(),
+                name if name.starts_with("fc:frame:") => {
+                    self.apply_tag(&name["fc:frame:".len()..], content, &mut temp_buttons)
+                }
+                _ => {}
             }
         }
 
@@ -153,7 +172,7 @@ impl Frame {
             Err(errs) => errors.add_errors(errs.errors),
         };
 
-        match self.validate() {
+        match self.validate_with(options) {
             Ok(_) => (),
             Err(e) => {
                 errors.add_errors(e.errors);
@@ -167,6 +186,46 @@ impl Frame {
         Ok(self)
     }
 
+    /// Applies a tag named without its `fc:frame:` or `of:` prefix.
+    fn apply_tag(
+        &mut self,
+        name: &str,
+        content: &str,
+        temp_buttons: &mut HashMap<usize, FrameButton>,
+    ) {
+        let content = content.to_string();
+        match name {
+            "image" => self.image.url = content,
+            "image:aspect_ratio" => {
+                self.image.aspect_ratio = match content.as_str() {
+                    "1.91:1" => AspectRatio::OnePointNineToOne,
+                    "1:1" => AspectRatio::OneToOne,
+                    _ => AspectRatio::Error,
+                }
+            }
+            "post_url" => self.post_url = Some(content),
+            "input:text" => self.input_text = Some(content),
+            name if name.starts_with("button:") => {
+                let parts: Vec<&str> = name.split(':').collect();
+                if let Ok(idx) = parts[1].parse::<usize>() {
+                    let button = temp_buttons.entry(idx).or_insert_with(|| FrameButton {
+                        id: idx,
+                        label: String::new(),
+                        action: Some("post".to_string()),
+                        target: None,
+                    });
+                    match parts.get(2) {
+                        None => button.label = content,
+                        Some(&"action") => button.action = Some(content),
+                        Some(&"target") => button.target = Some(content),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn add_buttons_if_apply(
         &mut self,
         temp_buttons: HashMap<usize, FrameButton>,
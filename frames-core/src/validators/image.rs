@@ -1,19 +1,24 @@
 use crate::{
     types::{
         errors::{Error, ErrorCode, FrameErrors},
+        frame::ValidationOptions,
         image::{AspectRatio, FrameImage},
     },
-    URL_REGEX,
+    IMAGE_DATA_URI_REGEX,
 };
 
 impl FrameImage {
     pub fn validate(&self) -> Result<(), FrameErrors> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    pub fn validate_with(&self, options: &ValidationOptions) -> Result<(), FrameErrors> {
         let mut errors = FrameErrors::new();
 
         // validate image (jpg, png, gif)
 
-        // validate image url
-        if !URL_REGEX.is_match(&self.url) {
+        // validate image url, rendered images are inlined as data URIs
+        if !options.url_regex().is_match(&self.url) && !IMAGE_DATA_URI_REGEX.is_match(&self.url) {
             let error = Error {
                 description: "The URL provided is invalid.".to_string(),
                 code: ErrorCode::InvalidURL,
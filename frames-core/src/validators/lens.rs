@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use chrono::{TimeZone, Utc};
+use ethers::types::{
+    transaction::eip712::{Eip712, TypedData},
+    RecoveryMessage, Signature, H256, U256,
+};
+
+use crate::{
+    provider::lens::LensProfileProvider,
+    types::{
+        action::{Actor, FrameAction},
+        errors::{ErrorCode, FrameErrors},
+        lens::{LensDomain, LensFrameActionPayload, LENS_PROTOCOL},
+    },
+    validators::action::action_error,
+};
+
+impl LensFrameActionPayload {
+    /// Recovers the signer of the payload's `FrameData` for `domain`, checks its deadline, then
+    /// asks `profiles` whether the signer owns the profile or is one of its delegated executors.
+    /// `unixTimestamp` is not signed, so the action is timestamped when verified and payloads
+    /// without a deadline are rejected.
+    pub async fn verify(
+        &self,
+        domain: &LensDomain,
+        profiles: &dyn LensProfileProvider,
+    ) -> Result<FrameAction, FrameErrors> {
+        if self.client_protocol.split('@').next() != Some(LENS_PROTOCOL) {
+            return Err(action_error(
+                ErrorCode::InvalidActionPayload,
+                "Expected a lens@<version> client protocol.",
+            ));
+        }
+
+        let data = &self.untrusted_data;
+        let deadline = data
+            .deadline
+            .and_then(|deadline| Utc.timestamp_opt(deadline, 0).single())
+            .ok_or_else(|| {
+            action_error(
+                ErrorCode::InvalidActionPayload,
+                "The frame data is missing its signed deadline.",
+            )
+        })?;
+        let typed_data: TypedData = serde_json::from_value(data.typed_data(domain))
+            .map_err(|e| action_error(ErrorCode::InvalidActionPayload, &e.to_string()))?;
+        let hash = typed_data.encode_eip712().map_err(|_| {
+            action_error(ErrorCode::InvalidActionPayload, "The frame data cannot be hashed.")
+        })?;
+
+        let signer = Signature::from_str(&self.trusted_data.message_bytes)
+            .ok()
+            .and_then(|signature| signature.recover(RecoveryMessage::Hash(H256(hash))).ok())
+            .ok_or_else(|| {
+                action_error(ErrorCode::InvalidSignature, "The frame data signature is invalid.")
+            })?;
+
+        let now = Utc::now();
+        if deadline < now {
+            return Err(action_error(
+                ErrorCode::ExpiredAction,
+                "The frame data signature has expired.",
+            ));
+        }
+
+        let profile_id = U256::from_str_radix(data.profile_id.trim_start_matches("0x"), 16)
+            .map_err(|_| {
+                action_error(ErrorCode::InvalidActionPayload, "The profile id is not hex.")
+            })?;
+        match profiles.is_profile_signer(profile_id, signer).await {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(action_error(
+                    ErrorCode::UnregisteredSigner,
+                    &format!(
+                        "{:?} is neither the owner nor a delegated executor of profile {}.",
+                        signer, data.profile_id
+                    ),
+                ))
+            }
+            Err(e) => return Err(action_error(ErrorCode::FailedToCheckSigner, &e.to_string())),
+        }
+
+        Ok(FrameAction {
+            actor: Actor::Lens { profile_id: data.profile_id.clone(), pub_id: data.pub_id.clone() },
+            url: data.url.clone(),
+            button_index: data.button_index,
+            input_text: data.input_text.clone().filter(|text| !text.is_empty()),
+            state: data.state.clone().filter(|state| !state.is_empty()),
+            transaction_id: data.action_response.clone().filter(|id| !id.is_empty()),
+            address: None,
+            timestamp: now,
+            expires_at: Some(deadline),
+            message_hash: format!("0x{}", hex::encode(hash)),
+            signer: format!("{:?}", signer),
+        })
+    }
+}
@@ -0,0 +1,140 @@
+use ethers::{abi::Abi, contract::Contract, providers::Middleware};
+
+use crate::{
+    provider::chain::ChainRegistry,
+    types::{
+        button::FrameButton,
+        caip::Caip10Address,
+        errors::{Error, ErrorCode, FrameErrors},
+        frame::Frame,
+    },
+};
+
+const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+
+const ERC165_ABI: &str = r#"[{
+    "inputs":[{"internalType":"bytes4","name":"interfaceId","type":"bytes4"}],
+    "name":"supportsInterface",
+    "outputs":[{"internalType":"bool","name":"","type":"bool"}],
+    "stateMutability":"view","type":"function"
+}]"#;
+
+impl Frame {
+    /// Runs [`Frame::validate`] and then checks the target of every `mint` button against the
+    /// chains in `chains`, reporting all errors together.
+    pub async fn validate_with_chains<M: Middleware + 'static>(
+        &self,
+        chains: &ChainRegistry<M>,
+    ) -> Result<(), FrameErrors> {
+        let mut errors = match self.validate() {
+            Ok(_) => FrameErrors::new(),
+            Err(e) => e,
+        };
+
+        for button in &self.buttons {
+            match button.validate_mint(chains).await {
+                Ok(_) => (),
+                Err(e) => errors.add_errors(e.errors),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+impl FrameButton {
+    /// Checks that the CAIP-10 target of a `mint` button is on a supported chain and points at a
+    /// deployed ERC-721 or ERC-1155 contract. Other actions are always valid.
+    pub async fn validate_mint<M: Middleware + 'static>(
+        &self,
+        chains: &ChainRegistry<M>,
+    ) -> Result<(), FrameErrors> {
+        if self.action.as_deref() != Some("mint") {
+            return Ok(());
+        }
+
+        let key = format!("fc:frame:button:{}:target", self.id);
+        let target: Caip10Address = match self.target.as_deref().map(str::parse) {
+            Some(Ok(target)) => target,
+            Some(Err(description)) => {
+                return Err(mint_error(ErrorCode::InvalidMintTarget, description, &key))
+            }
+            None => {
+                return Err(mint_error(
+                    ErrorCode::InvalidMintTarget,
+                    "Mint buttons require a CAIP-10 target.".to_string(),
+                    &key,
+                ))
+            }
+        };
+
+        let middleware = match chains.get(target.chain_id) {
+            Some(middleware) => middleware,
+            None => {
+                return Err(mint_error(
+                    ErrorCode::UnsupportedChain,
+                    format!("Chain {} is not supported.", target.chain_id),
+                    &key,
+                ))
+            }
+        };
+
+        match middleware.get_code(target.address, None).await {
+            Ok(code) if code.is_empty() => {
+                return Err(mint_error(
+                    ErrorCode::MissingContract,
+                    format!("No contract is deployed at {}.", target),
+                    &key,
+                ))
+            }
+            Ok(_) => (),
+            Err(e) => {
+                return Err(mint_error(
+                    ErrorCode::FailedToQueryChain,
+                    format!("Failed to fetch the code at {}: {}", target, e),
+                    &key,
+                ))
+            }
+        }
+
+        let abi: Abi = serde_json::from_str(ERC165_ABI).expect("Parse ABI Error");
+        let contract = Contract::new(target.address, abi, middleware);
+        for interface_id in [ERC721_INTERFACE_ID, ERC1155_INTERFACE_ID] {
+            // contracts without ERC-165 revert, which counts as not supported
+            match contract
+                .method::<_, bool>("supportsInterface", interface_id)
+                .unwrap()
+                .call()
+                .await
+            {
+                Ok(true) => return Ok(()),
+                Ok(false) => (),
+                Err(e) if e.is_revert() => (),
+                Err(e) => {
+                    return Err(mint_error(
+                        ErrorCode::FailedToQueryChain,
+                        format!("Failed to query the interfaces of {}: {}", target, e),
+                        &key,
+                    ))
+                }
+            }
+        }
+
+        Err(mint_error(
+            ErrorCode::UnsupportedTokenStandard,
+            format!("{} does not support ERC-721 or ERC-1155.", target),
+            &key,
+        ))
+    }
+}
+
+fn mint_error(code: ErrorCode, description: String, key: &str) -> FrameErrors {
+    let mut errors = FrameErrors::new();
+    errors.add_error(Error { description, code, key: Some(key.to_string()) });
+    errors
+}
@@ -1,3 +1,13 @@
+pub mod action;
 pub mod button;
+pub mod cast_action;
+pub mod composer_action;
 pub mod frame;
 pub mod image;
+#[cfg(feature = "lens")]
+pub mod lens;
+pub mod mint;
+pub mod replay;
+pub mod url;
+#[cfg(feature = "xmtp")]
+pub mod xmtp;
@@ -0,0 +1,130 @@
+use std::{num::NonZeroUsize, sync::Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use lru::LruCache;
+
+use crate::{
+    types::{
+        action::{FrameAction, ValidatedFrameAction},
+        errors::{ErrorCode, FrameErrors},
+    },
+    validators::action::action_error,
+};
+
+/// Remembers the message hashes of handled actions, implemented by [`MemoryReplayStore`].
+/// Shared stores, e.g. backed by Redis, let several servers reject each other's replays.
+#[async_trait]
+pub trait ReplayStore: Send + Sync {
+    /// Marks `message_hash` as seen until `expires_at`. Returns `false` when it was already seen
+    /// and has not expired yet.
+    async fn insert(
+        &self,
+        message_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+}
+
+/// [`ReplayStore`] keeping the `capacity` most recently seen hashes in memory.
+pub struct MemoryReplayStore {
+    seen: Mutex<LruCache<String, DateTime<Utc>>>,
+}
+
+impl MemoryReplayStore {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        MemoryReplayStore { seen: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.seen.lock().expect("Replay Store Lock Error").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl ReplayStore for MemoryReplayStore {
+    async fn insert(
+        &self,
+        message_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut seen = self.seen.lock().expect("Replay Store Lock Error");
+        if let Some(expiry) = seen.get(message_hash) {
+            if *expiry > Utc::now() {
+                return Ok(false);
+            }
+        }
+        seen.put(message_hash.to_string(), expires_at);
+        Ok(true)
+    }
+}
+
+/// Rejects verified actions signed outside of a time window around now, and actions whose
+/// message hash was already handled within that window.
+pub struct ReplayGuard<S: ReplayStore> {
+    store: S,
+    max_age: Duration,
+    max_skew: Duration,
+}
+
+impl<S: ReplayStore> ReplayGuard<S> {
+    /// Accepts actions signed up to 10 minutes ago, or up to 1 minute in the future.
+    pub fn new(store: S) -> Self {
+        ReplayGuard { store, max_age: Duration::minutes(10), max_skew: Duration::minutes(1) }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Tolerance for clients whose clocks run ahead.
+    pub fn with_max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = max_skew;
+        self
+    }
+
+    pub async fn check(&self, action: &ValidatedFrameAction) -> Result<(), FrameErrors> {
+        self.check_message(action.timestamp, None, &action.message_hash).await
+    }
+
+    /// Actions with a signed deadline stay remembered until it passes, even when that is after
+    /// the window.
+    pub async fn check_action(&self, action: &FrameAction) -> Result<(), FrameErrors> {
+        self.check_message(action.timestamp, action.expires_at, &action.message_hash).await
+    }
+
+    async fn check_message(
+        &self,
+        timestamp: DateTime<Utc>,
+        deadline: Option<DateTime<Utc>>,
+        message_hash: &str,
+    ) -> Result<(), FrameErrors> {
+        let now = Utc::now();
+        if timestamp < now - self.max_age || timestamp > now + self.max_skew {
+            return Err(action_error(
+                ErrorCode::StaleAction,
+                &format!("The action was signed at {}, outside the accepted window.", timestamp),
+            ));
+        }
+
+        // older copies are rejected as stale or expired, so the hash only needs remembering
+        // until then
+        let expires_at = (timestamp + self.max_age).max(deadline.unwrap_or_default());
+        match self.store.insert(message_hash, expires_at).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(action_error(
+                ErrorCode::ReplayedAction,
+                &format!("The action {} has already been handled.", message_hash),
+            )),
+            Err(e) => Err(action_error(ErrorCode::FailedToCheckReplay, &e.to_string())),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use reqwest::Url;
+
+use crate::types::{
+    action::{FrameAction, ValidatedFrameAction},
+    errors::{Error, ErrorCode, FrameErrors},
+    frame::Frame,
+};
+
+/// Whether `url` is `expected` or lies under it: same scheme, host and port, and a path equal to
+/// or nested in the expected path. Trailing slashes, query strings and fragments are ignored.
+pub fn url_matches(url: &str, expected: &str) -> bool {
+    let (url, expected) = match (Url::parse(url), Url::parse(expected)) {
+        (Ok(url), Ok(expected)) => (url, expected),
+        _ => return false,
+    };
+    if url.scheme() != expected.scheme() ||
+        url.host_str() != expected.host_str() ||
+        url.port_or_known_default() != expected.port_or_known_default()
+    {
+        return false;
+    }
+
+    let path = url.path().trim_end_matches('/');
+    let prefix = expected.path().trim_end_matches('/');
+    path == prefix || path.starts_with(&format!("{}/", prefix))
+}
+
+impl ValidatedFrameAction {
+    /// Checks that the signed frame URL is under `expected`, so messages signed for someone
+    /// else's frame are not accepted by ours.
+    pub fn check_url(&self, expected: &str) -> Result<(), FrameErrors> {
+        check(&self.url, expected, None)
+    }
+}
+
+impl FrameAction {
+    pub fn check_url(&self, expected: &str) -> Result<(), FrameErrors> {
+        check(&self.url, expected, None)
+    }
+}
+
+impl Frame {
+    /// URL that clients POST to when `button_index` is pressed: the target of `post` and
+    /// `post_redirect` buttons that have one, or else the frame's `post_url`.
+    pub fn post_url_of(&self, button_index: u32) -> Option<&str> {
+        let button = self.buttons.iter().find(|button| button.id == button_index as usize);
+        let target = button
+            .filter(|button| {
+                matches!(button.action.as_deref(), None | Some("post") | Some("post_redirect"))
+            })
+            .and_then(|button| button.target.as_deref());
+        target.or(self.post_url.as_deref())
+    }
+
+    /// Checks that `request_url`, where an action for `button_index` was received, is the post
+    /// URL of that button.
+    pub fn check_post_url(&self, button_index: u32, request_url: &str) -> Result<(), FrameErrors> {
+        match self.post_url_of(button_index) {
+            Some(post_url) => check(request_url, post_url, Some("fc:frame:post_url")),
+            None => Err(url_error(
+                format!("Button {} has no post URL.", button_index),
+                Some("fc:frame:post_url"),
+            )),
+        }
+    }
+}
+
+fn check(url: &str, expected: &str, key: Option<&str>) -> Result<(), FrameErrors> {
+    if url_matches(url, expected) {
+        return Ok(());
+    }
+    Err(url_error(format!("The URL {} does not match {}.", url, expected), key))
+}
+
+fn url_error(description: String, key: Option<&str>) -> FrameErrors {
+    let mut errors = FrameErrors::new();
+    errors.add_error(Error {
+        description,
+        code: ErrorCode::UrlMismatch,
+        key: key.map(str::to_string),
+    });
+    errors
+}
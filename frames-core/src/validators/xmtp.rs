@@ -0,0 +1,133 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{TimeZone, Utc};
+use ethers::{
+    types::{Address, RecoveryMessage, Signature as EcdsaSignature, H256, U256},
+    utils::{hash_message, keccak256},
+};
+use prost::Message as ProstMessage;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    types::{
+        action::{Actor, FrameAction},
+        errors::{ErrorCode, FrameErrors},
+        xmtp::{
+            identity_signature_text, FrameAction as SignedFrameAction, FrameActionBody,
+            PublicKeyUnion, RecoverableSignature, SignatureUnion, SignedPublicKey,
+            UnsignedPublicKey, XmtpFrameActionPayload, XMTP_PROTOCOL,
+        },
+    },
+    validators::action::action_error,
+};
+
+impl XmtpFrameActionPayload {
+    pub fn verify(&self) -> Result<FrameAction, FrameErrors> {
+        if self.client_protocol.split('@').next() != Some(XMTP_PROTOCOL) {
+            return Err(action_error(
+                ErrorCode::InvalidActionPayload,
+                "Expected an xmtp@<version> client protocol.",
+            ));
+        }
+
+        let bytes = STANDARD.decode(&self.trusted_data.message_bytes).map_err(|_| {
+            action_error(ErrorCode::InvalidMessageBytes, "messageBytes is not valid base64.")
+        })?;
+        let action = SignedFrameAction::decode(bytes.as_slice()).map_err(|_| {
+            action_error(
+                ErrorCode::InvalidMessageBytes,
+                "messageBytes is not a valid XMTP frame action.",
+            )
+        })?;
+
+        action.verify()
+    }
+}
+
+impl SignedFrameAction {
+    pub fn verify(&self) -> Result<FrameAction, FrameErrors> {
+        let body = FrameActionBody::decode(self.action_body.as_slice()).map_err(|_| {
+            action_error(ErrorCode::InvalidMessageBytes, "The action body cannot be decoded.")
+        })?;
+        let identity_key = self
+            .signed_public_key_bundle
+            .as_ref()
+            .and_then(|bundle| bundle.identity_key.as_ref())
+            .ok_or_else(|| {
+                action_error(ErrorCode::InvalidMessageBytes, "The action has no identity key.")
+            })?;
+
+        let digest: [u8; 32] = Sha256::digest(&self.action_body).into();
+        let signer = identity_address(identity_key).ok_or_else(|| {
+            action_error(ErrorCode::InvalidMessageBytes, "The identity key is not secp256k1.")
+        })?;
+        let signed_by_identity = match self.signature.as_ref().and_then(|s| s.union.as_ref()) {
+            Some(SignatureUnion::EcdsaCompact(signature)) => {
+                recover(signature, RecoveryMessage::Hash(H256(digest))) == Some(signer)
+            }
+            _ => false,
+        };
+        if !signed_by_identity {
+            return Err(action_error(
+                ErrorCode::InvalidSignature,
+                "The action is not signed by its identity key.",
+            ));
+        }
+
+        let wallet_address = match identity_key.signature.as_ref().and_then(|s| s.union.as_ref()) {
+            Some(SignatureUnion::WalletEcdsaCompact(signature)) => {
+                let text = identity_signature_text(&identity_key.key_bytes);
+                recover(signature, RecoveryMessage::Hash(hash_message(text)))
+            }
+            _ => None,
+        }
+        .ok_or_else(|| {
+            action_error(ErrorCode::InvalidSignature, "The identity key is not signed by a wallet.")
+        })?;
+
+        let timestamp =
+            Utc.timestamp_millis_opt(body.timestamp as i64).single().unwrap_or_default();
+
+        Ok(FrameAction {
+            actor: Actor::Xmtp {
+                wallet_address: format!("{:?}", wallet_address),
+                opaque_conversation_identifier: body.opaque_conversation_identifier,
+            },
+            url: body.frame_url,
+            button_index: body.button_index.max(0) as u32,
+            input_text: non_empty(body.input_text),
+            state: non_empty(body.state),
+            transaction_id: non_empty(body.transaction_id),
+            address: non_empty(body.address),
+            timestamp,
+            expires_at: None,
+            message_hash: format!("0x{}", hex::encode(digest)),
+            signer: format!("{:?}", signer),
+        })
+    }
+}
+
+fn identity_address(key: &SignedPublicKey) -> Option<Address> {
+    let key = UnsignedPublicKey::decode(key.key_bytes.as_slice()).ok()?;
+    match key.union? {
+        PublicKeyUnion::Secp256k1Uncompressed(point) if point.bytes.len() == 65 => {
+            Some(Address::from_slice(&keccak256(&point.bytes[1..])[12..]))
+        }
+        _ => None,
+    }
+}
+
+fn recover(signature: &RecoverableSignature, message: RecoveryMessage) -> Option<Address> {
+    if signature.bytes.len() != 64 || signature.recovery > 1 {
+        return None;
+    }
+    let signature = EcdsaSignature {
+        r: U256::from_big_endian(&signature.bytes[..32]),
+        s: U256::from_big_endian(&signature.bytes[32..]),
+        v: 27 + u64::from(signature.recovery),
+    };
+    signature.recover(message).ok()
+}
+
+fn non_empty(value: String) -> Option<String> {
+    Some(value).filter(|value| !value.is_empty())
+}
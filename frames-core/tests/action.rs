@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use ed25519_dalek::{Signer, SigningKey};
+    use frames_core::{
+        provider::signer::SignerProvider,
+        types::{
+            errors::ErrorCode,
+            external::{
+                FrameActionPayload, PayloadCastId, PayloadTrustedData, PayloadUntrustedData,
+            },
+            message::{
+                CastId, FarcasterNetwork, FrameActionBody, HashScheme, Message, MessageBody,
+                MessageData, MessageType, SignatureScheme,
+            },
+        },
+    };
+    use prost::Message as ProstMessage;
+
+    const KEY: [u8; 32] = [7u8; 32];
+
+    /// Knows fid 2 signs with [`KEY`], and fails for fid 3.
+    struct Signers;
+
+    #[async_trait]
+    impl SignerProvider for Signers {
+        async fn is_active_signer(
+            &self,
+            fid: u64,
+            signer: &[u8],
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            match fid {
+                3 => Err("hub unavailable".into()),
+                _ => {
+                    Ok(fid == 2 &&
+                        signer == SigningKey::from_bytes(&KEY).verifying_key().as_bytes())
+                }
+            }
+        }
+    }
+
+    fn signed_message(body: FrameActionBody) -> Message {
+        signed_message_of(2, body)
+    }
+
+    fn signed_message_of(fid: u64, body: FrameActionBody) -> Message {
+        let key = SigningKey::from_bytes(&KEY);
+        let data = MessageData {
+            r#type: MessageType::FrameAction as i32,
+            fid,
+            timestamp: 100_000_000,
+            network: FarcasterNetwork::Mainnet as i32,
+            body: Some(MessageBody::FrameActionBody(body)),
+        };
+        let hash = blake3::hash(&data.encode_to_vec()).as_bytes()[..20].to_vec();
+        Message {
+            data: Some(data),
+            signature: key.sign(&hash).to_bytes().to_vec(),
+            hash,
+            hash_scheme: HashScheme::Blake3 as i32,
+            signature_scheme: SignatureScheme::Ed25519 as i32,
+            signer: key.verifying_key().to_bytes().to_vec(),
+            data_bytes: None,
+        }
+    }
+
+    fn payload(message: &Message) -> FrameActionPayload {
+        FrameActionPayload {
+            trusted_data: PayloadTrustedData {
+                message_bytes: hex::encode(message.encode_to_vec()),
+            },
+            untrusted_data: PayloadUntrustedData {
+                fid: 2,
+                url: "https://example.com".to_string(),
+                message_hash: format!("0x{}", hex::encode(&message.hash)),
+                timestamp: chrono::Utc::now(),
+                network: 1,
+                button_index: 2,
+                cast_id: PayloadCastId { fid: 1, hash: "0x00".to_string() },
+                input_text: None,
+                state: None,
+                transaction_id: None,
+                address: None,
+            },
+        }
+    }
+
+    fn frame_action_body() -> FrameActionBody {
+        FrameActionBody {
+            url: b"https://example.com".to_vec(),
+            button_index: 2,
+            cast_id: Some(CastId { fid: 1, hash: vec![0xab; 20] }),
+            input_text: b"hello".to_vec(),
+            state: Vec::new(),
+            transaction_id: Vec::new(),
+            address: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_verifies_a_signed_frame_action() {
+        let message = signed_message(frame_action_body());
+        let action = payload(&message).verify(&Signers).await.unwrap();
+
+        assert_eq!(action.fid, 2);
+        assert_eq!(action.url, "https://example.com");
+        assert_eq!(action.button_index, 2);
+        assert_eq!(action.input_text, Some("hello".to_string()));
+        assert_eq!(action.state, None);
+        assert_eq!(action.cast_id.unwrap().hash, format!("0x{}", "ab".repeat(20)));
+        assert_eq!(action.timestamp.timestamp(), 1_609_459_200 + 100_000_000);
+        assert_eq!(action.message_hash, format!("0x{}", hex::encode(&message.hash)));
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_invalid_message_bytes() {
+        let mut payload = payload(&signed_message(frame_action_body()));
+        payload.trusted_data.message_bytes = "not hex".to_string();
+
+        let errors = payload.verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::InvalidMessageBytes);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_tampered_message_data() {
+        let mut message = signed_message(frame_action_body());
+        if let Some(MessageBody::FrameActionBody(body)) =
+            message.data.as_mut().and_then(|data| data.body.as_mut())
+        {
+            body.button_index = 3;
+        }
+
+        let errors = payload(&message).verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::InvalidMessageHash);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_invalid_signature() {
+        let mut message = signed_message(frame_action_body());
+        message.signature[0] ^= 0xff;
+
+        let errors = payload(&message).verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::InvalidSignature);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_non_frame_action_messages() {
+        let mut message = signed_message(frame_action_body());
+        message.data.as_mut().unwrap().r#type = MessageType::CastAdd as i32;
+
+        let errors = payload(&message).verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::InvalidMessageType);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_signers_not_registered_to_the_fid() {
+        let message = signed_message_of(5, frame_action_body());
+        let errors = payload(&message).verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnregisteredSigner);
+
+        let message = signed_message_of(3, frame_action_body());
+        let errors = payload(&message).verify(&Signers).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::FailedToCheckSigner);
+    }
+}
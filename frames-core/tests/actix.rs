@@ -0,0 +1,121 @@
+#[cfg(all(test, feature = "actix"))]
+mod tests {
+    use actix_web::{body, http::StatusCode, test::TestRequest, FromRequest, Responder};
+    use frames_core::types::{
+        action::ValidatedFrameAction,
+        button::FrameButton,
+        frame::Frame,
+        image::{AspectRatio, FrameImage},
+    };
+
+    #[tokio::test]
+    async fn it_responds_with_the_frame_html() {
+        let frame = Frame {
+            title: "Example".to_string(),
+            version: "vNext".to_string(),
+            image: FrameImage {
+                url: "https://example.com/image.png".to_string(),
+                aspect_ratio: AspectRatio::None,
+            },
+            buttons: vec![FrameButton {
+                id: 1,
+                label: "Start".to_string(),
+                action: None,
+                target: None,
+            }],
+            post_url: None,
+            input_text: None,
+            open_frames: None,
+        };
+
+        let req = TestRequest::default().to_http_request();
+        let response = frame.respond_to(&req);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("content-type").unwrap(), "text/html; charset=utf-8");
+
+        let html = body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(
+            html,
+            r#"<!DOCTYPE html><html><head><title>Example</title><meta name="fc:frame" content="vNext" /><meta name="fc:frame:image" content="https://example.com/image.png" /><meta name="fc:frame:button:1" content="Start" /></head><body></body></html>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn it_rejects_malformed_action_payloads_with_bad_request() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .set_payload(r#"{"untrustedData": {}}"#)
+            .to_http_parts();
+
+        let error = ValidatedFrameAction::from_request(&req, &mut payload).await.err().unwrap();
+        let response = actix_web::ResponseError::error_response(&error);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let json = body::to_bytes(response.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["errors"][0]["code"], "InvalidActionPayload");
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn it_extracts_verified_frame_actions() {
+        use frames_core::{
+            testing::signer::TestSigner, types::errors::ErrorCode,
+            validators::action::FrameActionVerifier,
+        };
+
+        let json = TestSigner::new(5).action("https://example.com").button_index(3).json();
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .app_data(FrameActionVerifier::new(TestSigner::new(5)))
+            .set_payload(json.clone())
+            .to_http_parts();
+
+        let action = ValidatedFrameAction::from_request(&req, &mut payload).await.unwrap();
+        assert_eq!(action.fid, 5);
+        assert_eq!(action.button_index, 3);
+
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .app_data(FrameActionVerifier::new(TestSigner::new(6)))
+            .set_payload(json.clone())
+            .to_http_parts();
+        let errors = ValidatedFrameAction::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnregisteredSigner);
+
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .set_payload(json)
+            .to_http_parts();
+        let errors = ValidatedFrameAction::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(errors.errors[0].code, ErrorCode::FailedToCheckSigner);
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn it_extracts_actions_of_any_protocol() {
+        use frames_core::{
+            testing::signer::TestSigner,
+            types::action::{Actor, FrameAction},
+            validators::action::FrameActionVerifier,
+        };
+
+        let payload = TestSigner::new(5).action("https://example.com").json();
+        let (req, mut body) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .app_data(actix_web::web::Data::new(FrameActionVerifier::new(TestSigner::new(5))))
+            .set_payload(payload)
+            .to_http_parts();
+
+        let action = FrameAction::from_request(&req, &mut body).await.unwrap();
+        assert!(matches!(action.actor, Actor::Farcaster { fid: 5, .. }));
+
+        let (req, mut body) = TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .app_data(FrameActionVerifier::new(TestSigner::new(5)))
+            .set_payload(r#"{"clientProtocol": "unknown@1"}"#)
+            .to_http_parts();
+        let errors = FrameAction::from_request(&req, &mut body).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, frames_core::types::errors::ErrorCode::InvalidActionPayload);
+    }
+}
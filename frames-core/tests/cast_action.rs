@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use frames_core::types::{
+        cast_action::{install_url, ActionType, CastActionMetadata, CastActionResponse},
+        errors::ErrorCode,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_metadata() {
+        let metadata = CastActionMetadata::new("Upthumb", "thumbsup", "Give casts 'upthumbs'")
+            .about_url("https://example.com/about")
+            .post_url("https://example.com/actions/upthumb");
+        assert!(metadata.validate().is_ok());
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "name": "Upthumb",
+                "icon": "thumbsup",
+                "description": "Give casts 'upthumbs'",
+                "aboutUrl": "https://example.com/about",
+                "action": { "type": "post", "postUrl": "https://example.com/actions/upthumb" }
+            })
+        );
+        assert_eq!(serde_json::from_value::<CastActionMetadata>(json).unwrap(), metadata);
+
+        let json = serde_json::to_value(CastActionMetadata::new("Log", "log", "")).unwrap();
+        assert_eq!(json["action"], json!({ "type": "post" }));
+        assert!(json.get("aboutUrl").is_none());
+    }
+
+    #[test]
+    fn it_validates_metadata() {
+        let metadata = CastActionMetadata::new("A name that is far too long", "Thumbs Up", "")
+            .about_url("not a url");
+        let errors = metadata.validate().unwrap_err().errors;
+        let keys: Vec<_> = errors.iter().map(|e| e.key.as_deref().unwrap()).collect();
+        assert_eq!(keys, ["name", "icon", "aboutUrl"]);
+        assert_eq!(errors[0].code, ErrorCode::InvalidCastAction);
+        assert_eq!(errors[2].code, ErrorCode::InvalidURL);
+
+        let metadata = CastActionMetadata::new("Upthumb", "arrow-up-right", &"a".repeat(81));
+        let errors = metadata.validate().unwrap_err().errors;
+        assert_eq!(errors[0].key.as_deref(), Some("description"));
+
+        let mut metadata = CastActionMetadata::new("Upthumb", "thumbsup", "");
+        metadata.action.action_type = ActionType::Composer;
+        let errors = metadata.validate().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidCastAction);
+        assert_eq!(errors[0].key.as_deref(), Some("action.type"));
+    }
+
+    #[test]
+    fn it_builds_install_links() {
+        assert_eq!(
+            install_url("https://example.com/actions/upthumb?ref=a&b=1"),
+            "https://warpcast.com/~/add-cast-action?url=https%3A%2F%2Fexample.com%2Factions%2Fupthumb%3Fref%3Da%26b%3D1"
+        );
+    }
+
+    #[test]
+    fn it_serializes_responses() {
+        assert_eq!(
+            serde_json::to_value(CastActionResponse::message("Upthumbed!")).unwrap(),
+            json!({ "type": "message", "message": "Upthumbed!" })
+        );
+        assert_eq!(
+            serde_json::to_value(CastActionResponse::message_with_link(
+                "Saved",
+                "https://example.com/saved"
+            ))
+            .unwrap(),
+            json!({ "type": "message", "message": "Saved", "link": "https://example.com/saved" })
+        );
+        let frame = CastActionResponse::frame("https://example.com/frames/poll");
+        assert_eq!(
+            serde_json::to_value(&frame).unwrap(),
+            json!({ "type": "frame", "frameUrl": "https://example.com/frames/poll" })
+        );
+        assert!(frame.validate().is_ok());
+
+        let errors = CastActionResponse::message(&"a".repeat(81)).validate().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidCastAction);
+        assert_eq!(errors[0].key.as_deref(), Some("message"));
+        let errors = CastActionResponse::frame("/frames/poll").validate().unwrap_err().errors;
+        assert_eq!(errors[0].key.as_deref(), Some("frameUrl"));
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn it_verifies_signed_cast_action_payloads() {
+        use frames_core::{testing::signer::TestSigner, types::external::CastActionPayload};
+
+        let hash = "0x0000000000000000000000000000000000000abc";
+        let signer = TestSigner::new(3);
+        let json = serde_json::to_string(
+            &signer
+                .action("https://example.com/actions/upthumb")
+                .cast_id(42, hash)
+                .cast_action_payload(),
+        )
+        .unwrap();
+
+        let payload: CastActionPayload = serde_json::from_str(&json).unwrap();
+        let action = payload.verify(&signer).await.unwrap();
+        assert_eq!(action.fid, 3);
+        assert_eq!(action.url, "https://example.com/actions/upthumb");
+        assert_eq!(action.cast_id.fid, 42);
+        assert_eq!(action.cast_id.hash, hash);
+        assert_eq!(action.message_hash, payload.untrusted_data.message_hash);
+
+        let mut payload = payload;
+        payload.trusted_data.message_bytes = "0xzz".to_string();
+        let errors = payload.verify(&signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidMessageBytes);
+    }
+}
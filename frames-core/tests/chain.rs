@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use ethers::providers::{Http, Provider};
+    use frames_core::provider::chain::{ChainRegistry, UnsupportedChain, FARCASTER_CHAIN_ID};
+
+    #[tokio::test]
+    async fn it_registers_a_middleware_per_chain() {
+        let registry = ChainRegistry::<Provider<Http>>::new()
+            .with_rpc_url(8453, "https://mainnet.base.org")
+            .unwrap()
+            .with_rpc_url(FARCASTER_CHAIN_ID, "https://mainnet.optimism.io")
+            .unwrap();
+
+        assert_eq!(registry.chain_ids(), vec![10, 8453]);
+        assert!(registry.supports(8453));
+        assert!(!registry.supports(1));
+        assert_eq!(registry.middleware(1).err(), Some(UnsupportedChain(1)));
+        assert!(registry.with_rpc_url(1, "not a url").is_err());
+    }
+
+    #[cfg(feature = "testing")]
+    mod fixtures {
+        use ethers::{
+            abi::{self, Token},
+            providers::Middleware,
+            types::{Address, Bytes, U256},
+            utils::id,
+        };
+        use frames_core::{
+            provider::{chain::ChainRegistry, hub::HubClient},
+            testing::{
+                evm::{mock_middleware, Fixtures, MockMiddleware},
+                http::HttpStub,
+            },
+            types::token::TokenRequirement,
+        };
+
+        const CUSTODY: &str = "0x8773442740C17C9d0F0B87022c722F9a136206eD";
+
+        fn registry() -> ChainRegistry<MockMiddleware> {
+            let custody: Address = CUSTODY.parse().unwrap();
+            let balance_of: Bytes =
+                [&id("balanceOf(address)")[..], &abi::encode(&[Token::Address(custody)])]
+                    .concat()
+                    .into();
+            let base = Fixtures::new(8453).with_call(
+                Address::from_low_u64_be(0x70),
+                balance_of,
+                abi::encode(&[Token::Uint(5.into())]),
+            );
+            let optimism = Fixtures::load(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/optimism.json"
+            ))
+            .unwrap();
+
+            ChainRegistry::new()
+                .with_chain(10, mock_middleware(optimism))
+                .with_chain(8453, mock_middleware(base))
+        }
+
+        #[tokio::test]
+        async fn it_binds_farcaster_lookups_to_op_mainnet() {
+            let registry = registry();
+
+            let farcaster = registry.farcaster().unwrap();
+            assert_eq!(farcaster.inner.get_chainid().await.unwrap().as_u64(), 10);
+            assert_eq!(
+                farcaster.get_custody_address_by_fid(1).await.unwrap(),
+                Some(CUSTODY.parse().unwrap())
+            );
+            let base = registry.middleware(8453).unwrap();
+            assert_eq!(base.get_chainid().await.unwrap().as_u64(), 8453);
+        }
+
+        #[tokio::test]
+        async fn it_checks_token_gates_on_other_chains() {
+            let hub = HttpStub::start().await;
+            hub.route("/v1/verificationsByFid?fid=1", 200, r#"{"messages":[]}"#);
+            let requirement =
+                TokenRequirement::erc20(Address::from_low_u64_be(0x70), U256::from(5));
+
+            let result = registry()
+                .check_token_gate(8453, 1, &HubClient::new(&hub.url), &requirement)
+                .await
+                .unwrap();
+            assert!(result.passed);
+            assert_eq!(result.balances, vec![(CUSTODY.parse().unwrap(), U256::from(5))]);
+
+            let result =
+                registry().check_token_gate(1, 1, &HubClient::new(&hub.url), &requirement).await;
+            assert!(result.is_err());
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use frames_core::types::{
+        cast_action::ActionType,
+        composer_action::{
+            CastDraft, ComposerActionMetadata, ComposerActionResponse, ComposerActionState,
+        },
+        errors::ErrorCode,
+    };
+    use serde_json::json;
+
+    fn state() -> ComposerActionState {
+        ComposerActionState {
+            request_id: None,
+            cast: CastDraft {
+                text: "Vote: tabs & spaces? 100%".to_string(),
+                embeds: vec!["https://example.com/frames/poll?id=1".to_string()],
+                parent: Some("https://warpcast.com/~/channel/rust".to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn it_serializes_metadata() {
+        let metadata = ComposerActionMetadata::new("Poll", "checkbox", "Create a poll frame")
+            .about_url("https://example.com/about")
+            .image_url("https://example.com/poll.png");
+        assert!(metadata.validate().is_ok());
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "composer",
+                "name": "Poll",
+                "icon": "checkbox",
+                "description": "Create a poll frame",
+                "aboutUrl": "https://example.com/about",
+                "imageUrl": "https://example.com/poll.png",
+                "action": { "type": "post" }
+            })
+        );
+        assert_eq!(serde_json::from_value::<ComposerActionMetadata>(json).unwrap(), metadata);
+    }
+
+    #[test]
+    fn it_validates_metadata() {
+        let mut metadata = ComposerActionMetadata::new("", "checkbox", "Create a poll frame")
+            .image_url("poll.png");
+        metadata.action_type = ActionType::Post;
+        let errors = metadata.validate().unwrap_err().errors;
+        let keys: Vec<_> = errors.iter().map(|e| e.key.as_deref().unwrap()).collect();
+        assert_eq!(keys, ["type", "name", "imageUrl"]);
+        assert_eq!(errors[0].code, ErrorCode::InvalidComposerAction);
+        assert_eq!(errors[2].code, ErrorCode::InvalidURL);
+    }
+
+    #[test]
+    fn it_serializes_form_responses() {
+        let form = ComposerActionResponse::form("Create a poll", "https://example.com/polls/new");
+        assert_eq!(
+            serde_json::to_value(&form).unwrap(),
+            json!({ "type": "form", "title": "Create a poll", "url": "https://example.com/polls/new" })
+        );
+        assert!(form.validate().is_ok());
+
+        let errors = ComposerActionResponse::form("", "/polls/new").validate().unwrap_err().errors;
+        let keys: Vec<_> = errors.iter().map(|e| e.key.as_deref().unwrap()).collect();
+        assert_eq!(keys, ["title", "url"]);
+        assert_eq!(errors[0].code, ErrorCode::InvalidComposerAction);
+    }
+
+    #[test]
+    fn it_round_trips_encoded_state() {
+        let encoded = state().encode();
+        assert!(encoded.starts_with("%7B%22cast%22%3A%7B%22text%22%3A%22Vote%3A%20tabs%20%26"));
+        assert_eq!(ComposerActionState::decode(&encoded).unwrap(), state());
+
+        let decoded = ComposerActionState::decode(
+            "%7B%22requestId%22%3A%22abc%22%2C%22cast%22%3A%7B%22text%22%3A%22gm%22%7D%7D",
+        )
+        .unwrap();
+        assert_eq!(decoded.request_id.as_deref(), Some("abc"));
+        assert_eq!(decoded.cast, CastDraft { text: "gm".to_string(), ..Default::default() });
+
+        let errors = ComposerActionState::decode("%7B%22cast%22").unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn it_verifies_the_signed_draft() {
+        use frames_core::{testing::signer::TestSigner, types::external::ComposerActionPayload};
+
+        let signer = TestSigner::new(5);
+        let json = serde_json::to_string(
+            &signer
+                .action("https://example.com/actions/poll")
+                .state(&state().encode())
+                .composer_action_payload(),
+        )
+        .unwrap();
+
+        let mut payload: ComposerActionPayload = serde_json::from_str(&json).unwrap();
+        let action = payload.verify(&signer).await.unwrap();
+        assert_eq!(action.fid, 5);
+        assert_eq!(action.url, "https://example.com/actions/poll");
+        assert_eq!(action.state, state());
+
+        // only the signed state is trusted
+        payload.untrusted_data.state = ComposerActionState {
+            request_id: None,
+            cast: CastDraft { text: "forged".to_string(), ..Default::default() },
+        }
+        .encode();
+        assert_eq!(payload.verify(&signer).await.unwrap().state, state());
+
+        let payload = signer.action("https://example.com/actions/poll").composer_action_payload();
+        let errors = payload.verify(&signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+    }
+}
@@ -3,7 +3,7 @@ mod tests {
     use frames_core::serializers::HtmlSerializer;
     use frames_core::types::button::FrameButton;
     use frames_core::types::errors::{Error, ErrorCode, FrameErrors};
-    use frames_core::types::frame::Frame;
+    use frames_core::types::frame::{Frame, ValidationOptions};
     use frames_core::types::image::{AspectRatio, FrameImage};
 
     #[test]
@@ -55,6 +55,7 @@ mod tests {
             ],
             post_url: Some("https://example.com".to_string()),
             input_text: Some("Enter a message".to_string()),
+            open_frames: None,
         };
 
         let mut frame_container = Frame::new();
@@ -102,6 +103,7 @@ mod tests {
             ],
             post_url: Some("https://example.com".to_string()),
             input_text: Some("Enter a message".to_string()),
+            open_frames: None,
         };
 
         let html_result = expected_frame.to_html();
@@ -127,6 +129,7 @@ mod tests {
             }],
             post_url: Some("https://pheml.vercel.app/api/frame".to_string()),
             input_text: None,
+            open_frames: None,
         };
 
         let mut frame_container = Frame::new();
@@ -173,6 +176,7 @@ mod tests {
             ],
             post_url: Some("https://pheml.vercel.app/api/frame".to_string()),
             input_text: None,
+            open_frames: None,
         };
 
         let mut frame_container = Frame::new();
@@ -209,6 +213,7 @@ mod tests {
             }],
             post_url: Some("https://pheml.vercel.app/api/frame".to_string()),
             input_text: None,
+            open_frames: None,
         };
 
         let mut frame_container = Frame::new();
@@ -295,4 +300,34 @@ mod tests {
         });
         assert_eq!(errors, expected_errors);
     }
+
+    #[test]
+    fn it_parses_link_buttons_served_from_localhost() {
+        let html = r#"
+             <title>Local</title>
+             <meta name="fc:frame" content="vNext"/>
+             <meta name="fc:frame:image" content="http://localhost:3000/image.png"/>
+             <meta name="fc:frame:post_url" content="http://localhost:3000/api/frame"/>
+             <meta name="fc:frame:button:1:action" content="link"/>
+             <meta name="fc:frame:button:1:target" content="https://example.com"/>
+             <meta name="fc:frame:button:1" content="Visit"/>
+        "#;
+
+        let errors = Frame::new().from_html(html).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidURL);
+
+        let mut frame_container = Frame::new();
+        let frame = frame_container.from_html_with(html, &ValidationOptions::local()).unwrap();
+
+        assert_eq!(
+            frame.buttons,
+            vec![FrameButton {
+                id: 1,
+                label: "Visit".to_string(),
+                action: Some("link".to_string()),
+                target: Some("https://example.com".to_string()),
+            }]
+        );
+        assert_eq!(frame.image.url, "http://localhost:3000/image.png");
+    }
 }
@@ -0,0 +1,129 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use frames_core::{
+        testing::signer::TestSigner,
+        types::{
+            action::{ActionCastId, Actor, FrameAction},
+            errors::ErrorCode,
+        },
+        validators::action::FrameActionVerifier,
+    };
+    use serde_json::{json, Value};
+
+    async fn verify(json: &str) -> Result<FrameAction, ErrorCode> {
+        let payload: Value = serde_json::from_str(json).unwrap();
+        let verifier = FrameActionVerifier::new(TestSigner::new(7));
+        verifier.verify(&payload).await.map_err(|mut e| e.errors.remove(0).code)
+    }
+
+    #[tokio::test]
+    async fn it_converts_farcaster_actions() {
+        let signer = TestSigner::new(7);
+        let payload = signer
+            .action("https://example.com")
+            .button_index(2)
+            .input_text("gm")
+            .address("0x00000000000000000000000000000000000000aa")
+            .cast_id(3, "0x0000000000000000000000000000000000000abc")
+            .payload();
+        let validated = payload.verify(&signer).await.unwrap();
+
+        let action = FrameAction::from(validated.clone());
+        let cast_id =
+            ActionCastId { fid: 3, hash: "0x0000000000000000000000000000000000000abc".to_string() };
+        assert_eq!(action.actor, Actor::Farcaster { fid: 7, cast_id: Some(cast_id) });
+        assert_eq!(action.actor.protocol(), "farcaster");
+        assert_eq!(action.actor.fid(), Some(7));
+        assert_eq!(action.url, validated.url);
+        assert_eq!(action.button_index, 2);
+        assert_eq!(action.input_text.as_deref(), Some("gm"));
+        assert_eq!(action.address, validated.address);
+        assert_eq!(action.timestamp, validated.timestamp);
+        assert_eq!(action.message_hash, validated.message_hash);
+        assert_eq!(action.signer, validated.signer);
+
+        let json = serde_json::to_value(&action).unwrap();
+        assert_eq!(
+            json["actor"],
+            json!({
+                "protocol": "farcaster",
+                "fid": 7,
+                "castId": { "fid": 3, "hash": "0x0000000000000000000000000000000000000abc" },
+            })
+        );
+
+        let mut validated = validated;
+        validated.cast_id = None;
+        let json = serde_json::to_value(FrameAction::from(validated)).unwrap();
+        assert_eq!(json["actor"], json!({ "protocol": "farcaster", "fid": 7 }));
+    }
+
+    #[tokio::test]
+    async fn it_dispatches_on_the_client_protocol() {
+        let signer = TestSigner::new(7);
+        let action = verify(&signer.action("https://example.com").json()).await.unwrap();
+        assert!(matches!(action.actor, Actor::Farcaster { fid: 7, .. }));
+
+        let mut payload: Value =
+            serde_json::from_str(&signer.action("https://example.com").json()).unwrap();
+        payload["clientProtocol"] = json!("farcaster@vNext");
+        assert_eq!(verify(&payload.to_string()).await.unwrap().actor.fid(), Some(7));
+
+        payload["clientProtocol"] = json!("bluesky@1");
+        assert_eq!(
+            verify(&payload.to_string()).await.unwrap_err(),
+            ErrorCode::InvalidActionPayload
+        );
+
+        assert_eq!(
+            verify(r#"{"untrustedData": {}}"#).await.unwrap_err(),
+            ErrorCode::InvalidActionPayload
+        );
+
+        let impostor = TestSigner::new(8).action("https://example.com").json();
+        assert_eq!(verify(&impostor).await.unwrap_err(), ErrorCode::UnregisteredSigner);
+    }
+
+    #[cfg(feature = "xmtp")]
+    #[tokio::test]
+    async fn it_converts_xmtp_actions() {
+        let signer = frames_core::testing::xmtp::XmtpTestSigner::new(1);
+        let json = signer.action("https://example.com").input_text("gm").json();
+        let action = verify(&json).await.unwrap();
+
+        let wallet_address = format!("{:?}", signer.wallet_address());
+        assert!(
+            matches!(&action.actor, Actor::Xmtp { wallet_address: address, .. } if *address == wallet_address)
+        );
+        assert_eq!(action.actor.fid(), None);
+        assert_eq!(action.input_text.as_deref(), Some("gm"));
+        assert_eq!(action.signer, format!("{:?}", signer.identity_address()));
+    }
+
+    #[cfg(feature = "lens")]
+    #[tokio::test]
+    async fn it_converts_lens_actions_with_a_configured_domain() {
+        use frames_core::testing::lens::LensTestSigner;
+
+        let signer = LensTestSigner::new(1);
+        let json = signer.action("https://example.com").profile_id("0x05").json();
+        let payload: Value = serde_json::from_str(&json).unwrap();
+
+        let verifier = FrameActionVerifier::new(TestSigner::new(7));
+        let errors = verifier.verify(&payload).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+
+        let verifier = verifier.with_lens(signer.domain.clone(), LensTestSigner::new(1));
+        let action = verifier.verify(&payload).await.unwrap();
+        let actor = Actor::Lens { profile_id: "0x05".to_string(), pub_id: "0x01-0x01".to_string() };
+        assert_eq!(action.actor, actor);
+        assert_eq!(action.actor.protocol(), "lens");
+        assert_eq!(action.signer, format!("{:?}", signer.address()));
+
+        // the profile is only the signer's when the verifier's LensHub says so
+        let verifier = FrameActionVerifier::new(TestSigner::new(7))
+            .with_lens(signer.domain.clone(), LensTestSigner::new(2));
+        let errors = verifier.verify(&payload).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UnregisteredSigner);
+    }
+}
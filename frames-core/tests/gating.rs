@@ -0,0 +1,138 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use ethers::{
+        abi::{self, Token},
+        contract::MULTICALL_ADDRESS,
+        providers::Provider,
+        types::{Address, Bytes, U256},
+        utils::id,
+    };
+    use frames_core::{
+        provider::{farcaster::FarcasterProvider, hub::HubClient},
+        testing::{
+            evm::{mock_middleware, FixtureClient, Fixtures, RecordingClient},
+            http::HttpStub,
+        },
+        types::token::TokenRequirement,
+    };
+
+    const CUSTODY: &str = "0x8773442740C17C9d0F0B87022c722F9a136206eD";
+    const VERIFIED: &str = "0xd7029bdea1c17493893aafe29aad69ef892b8ff2";
+
+    fn balance_of(owner: Address) -> Bytes {
+        [&id("balanceOf(address)")[..], &abi::encode(&[Token::Address(owner)])].concat().into()
+    }
+
+    fn balance_of_id(owner: Address, token_id: u64) -> Bytes {
+        let args = abi::encode(&[Token::Address(owner), Token::Uint(token_id.into())]);
+        [&id("balanceOf(address,uint256)")[..], &args].concat().into()
+    }
+
+    fn uint(value: u64) -> Bytes {
+        abi::encode(&[Token::Uint(value.into())]).into()
+    }
+
+    fn token() -> Address {
+        Address::from_low_u64_be(0x70)
+    }
+
+    fn fixtures() -> Fixtures {
+        let (custody, verified) = (CUSTODY.parse().unwrap(), VERIFIED.parse().unwrap());
+        Fixtures::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/optimism.json"))
+            .unwrap()
+            .with_call(token(), balance_of(custody), uint(40))
+            .with_call(token(), balance_of(verified), uint(70))
+            .with_call(token(), balance_of_id(custody, 7), uint(0))
+            .with_call(token(), balance_of_id(verified, 7), uint(2))
+    }
+
+    async fn hub() -> HttpStub {
+        let hub = HttpStub::start().await;
+        hub.route(
+            "/v1/verificationsByFid?fid=1",
+            200,
+            &format!(
+                r#"{{"messages":[{{"data":{{"type":"MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS","fid":1,"timestamp":1,"verificationAddAddressBody":{{"address":"{}","protocol":"PROTOCOL_ETHEREUM"}}}},"hash":"0x01"}}]}}"#,
+                VERIFIED
+            ),
+        );
+        hub
+    }
+
+    #[tokio::test]
+    async fn it_checks_erc20_balances_across_all_addresses_of_a_fid() {
+        let hub = hub().await;
+        let provider = FarcasterProvider::new(mock_middleware(fixtures()));
+
+        let requirement = TokenRequirement::erc20(token(), U256::from(100));
+        let result =
+            provider.check_token_gate(1, &HubClient::new(&hub.url), &requirement).await.unwrap();
+        assert!(result.passed);
+        assert_eq!(result.total_balance(), U256::from(110));
+        assert_eq!(result.balances[0], (CUSTODY.parse().unwrap(), U256::from(40)));
+
+        let requirement = TokenRequirement::erc20(token(), U256::from(111));
+        let result =
+            provider.check_token_gate(1, &HubClient::new(&hub.url), &requirement).await.unwrap();
+        assert!(!result.passed);
+    }
+
+    #[tokio::test]
+    async fn it_checks_erc721_ownership_and_erc1155_balances() {
+        let hub = hub().await;
+        let provider = FarcasterProvider::new(mock_middleware(fixtures()));
+        let hub = HubClient::new(&hub.url);
+
+        let erc721 = TokenRequirement::erc721(token());
+        assert!(provider.check_token_gate(1, &hub, &erc721).await.unwrap().passed);
+
+        let erc1155 = TokenRequirement::erc1155(token(), U256::from(7), U256::from(3));
+        let result = provider.check_token_gate(1, &hub, &erc1155).await.unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.total_balance(), U256::from(2));
+    }
+
+    #[tokio::test]
+    async fn it_batches_balance_checks_into_a_single_multicall() {
+        let recorder = RecordingClient::new(FixtureClient::new(fixtures()), 10);
+        let provider = FarcasterProvider::new(Provider::new(recorder));
+        let addresses = [CUSTODY.parse().unwrap(), VERIFIED.parse().unwrap()];
+
+        let balances = provider
+            .get_token_balances(&addresses, &TokenRequirement::erc20(token(), U256::one()))
+            .await
+            .unwrap();
+        assert_eq!(balances, vec![U256::from(40), U256::from(70)]);
+
+        let calls = provider.inner.as_ref().as_ref().fixtures().calls;
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].to, MULTICALL_ADDRESS);
+    }
+
+    #[tokio::test]
+    async fn it_reverts_multicalls_whose_required_calls_fail() {
+        use ethers::contract::Multicall;
+        use std::sync::Arc;
+
+        let (custody, stranger) = (CUSTODY.parse().unwrap(), Address::from_low_u64_be(1));
+        let client = Arc::new(mock_middleware(fixtures().with_revert(
+            token(),
+            balance_of(stranger),
+            Bytes::default(),
+        )));
+        let abi = abi::parse_abi(&["function balanceOf(address) view returns (uint256)"]).unwrap();
+        let contract = ethers::contract::Contract::new(token(), abi, client.clone());
+        let balance_of = |owner: Address| contract.method::<_, U256>("balanceOf", owner).unwrap();
+
+        let mut multicall = Multicall::new(client.clone(), Some(MULTICALL_ADDRESS)).await.unwrap();
+        multicall.add_call(balance_of(custody), false).add_call(balance_of(stranger), true);
+        let results = multicall.call_raw().await.unwrap();
+        assert_eq!(results[0].as_ref().unwrap(), &Token::Uint(40.into()));
+        assert!(results[1].is_err());
+
+        let mut multicall = Multicall::new(client, Some(MULTICALL_ADDRESS)).await.unwrap();
+        multicall.add_call(balance_of(custody), true).add_call(balance_of(stranger), false);
+        let error = multicall.call_raw().await.unwrap_err();
+        assert_eq!(error.decode_revert::<String>().as_deref(), Some("Multicall3: call failed"));
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use ethers::types::Address;
+    use frames_core::{provider::hub::HubClient, testing::http::HttpStub};
+
+    fn verification(address: &str, protocol: &str) -> String {
+        format!(
+            r#"{{"data":{{"type":"MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS","fid":2,"timestamp":1,"verificationAddAddressBody":{{"address":"{}","protocol":"{}"}}}},"hash":"0x01"}}"#,
+            address, protocol
+        )
+    }
+
+    #[tokio::test]
+    async fn it_get_verified_addresses_across_pages() {
+        let hub = HttpStub::start().await;
+        hub.route(
+            "/v1/verificationsByFid?fid=2",
+            200,
+            &format!(
+                r#"{{"messages":[{}],"nextPageToken":"next"}}"#,
+                verification("0x0000000000000000000000000000000000000001", "PROTOCOL_ETHEREUM")
+            ),
+        );
+        hub.route(
+            "/v1/verificationsByFid?fid=2&pageToken=next",
+            200,
+            &format!(
+                r#"{{"messages":[{},{}],"nextPageToken":""}}"#,
+                verification("7UX2i7SucgLMQcfZ75s3VXmZZY4YRUyJN9X1RgfMoDUi", "PROTOCOL_SOLANA"),
+                verification("0x0000000000000000000000000000000000000002", "PROTOCOL_ETHEREUM")
+            ),
+        );
+
+        let addresses = HubClient::new(&hub.url).get_verified_addresses(2).await.unwrap();
+        assert_eq!(addresses, vec![Address::from_low_u64_be(1), Address::from_low_u64_be(2)]);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_hub_fails() {
+        let hub = HttpStub::start().await;
+        hub.route("/v1/verificationsByFid?fid=2", 500, "{}");
+
+        assert!(HubClient::new(&hub.url).get_verified_addresses(2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_checks_signers_against_their_onchain_events() {
+        let key = format!("0x{}", "ab".repeat(32));
+        let hub = HttpStub::start().await;
+        hub.route(
+            &format!("/v1/onChainSignersByFid?fid=2&signer={}", key),
+            200,
+            &format!(
+                r#"{{"type":"EVENT_TYPE_SIGNER","fid":2,"signerEventBody":{{"key":"{}","keyType":1,"eventType":"SIGNER_EVENT_TYPE_ADD"}}}}"#,
+                key
+            ),
+        )
+        .route(
+            &format!("/v1/onChainSignersByFid?fid=3&signer={}", key),
+            400,
+            r#"{"errCode":"not_found","message":"no such signer"}"#,
+        )
+        .route(&format!("/v1/onChainSignersByFid?fid=4&signer={}", key), 500, "{}");
+
+        let hub = HubClient::new(&hub.url);
+        assert!(hub.is_active_signer(2, &[0xab; 32]).await.unwrap());
+        assert!(!hub.is_active_signer(3, &[0xab; 32]).await.unwrap());
+        assert!(hub.is_active_signer(4, &[0xab; 32]).await.is_err());
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use frames_core::{
+        image_url::ImageUrlSigner,
+        types::{errors::ErrorCode, image::AspectRatio},
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Poll {
+        question: String,
+        votes: Vec<u32>,
+    }
+
+    fn poll() -> Poll {
+        Poll { question: "Tabs or spaces?".to_string(), votes: vec![3, 5] }
+    }
+
+    fn signer() -> ImageUrlSigner {
+        ImageUrlSigner::new("https://example.com/api/image?theme=dark", "secret")
+    }
+
+    #[test]
+    fn it_round_trips_signed_params() {
+        let url = signer().url(&poll()).unwrap();
+        assert!(url.starts_with("https://example.com/api/image?theme=dark&p="));
+        assert!(!url.contains("&t="));
+
+        let verified = signer().verify::<Poll>(&url).unwrap();
+        assert_eq!(verified.params, poll());
+        assert_eq!(verified.timestamp, None);
+
+        // image routes usually only see the path and query
+        let path = url.trim_start_matches("https://example.com");
+        assert_eq!(signer().verify::<Poll>(path).unwrap().params, poll());
+    }
+
+    #[test]
+    fn it_busts_caches_with_timestamps() {
+        let now = Utc::now();
+        let first = signer().url_at(&poll(), now).unwrap();
+        let second = signer().url_at(&poll(), now + Duration::seconds(1)).unwrap();
+        assert_ne!(first, second);
+
+        let verified = signer().verify::<Poll>(&second).unwrap();
+        assert_eq!(verified.timestamp.unwrap().timestamp(), now.timestamp() + 1);
+
+        let image = signer().frame_image(&poll(), AspectRatio::OneToOne).unwrap();
+        assert_eq!(image.aspect_ratio, AspectRatio::OneToOne);
+        assert!(image.validate().is_ok());
+        assert!(signer().verify::<Poll>(&image.url).unwrap().timestamp.is_some());
+    }
+
+    #[test]
+    fn it_rejects_tampered_urls() {
+        let url = signer().url_at(&poll(), Utc::now()).unwrap();
+
+        let other = ImageUrlSigner::new("https://example.com/api/image", "other secret");
+        let errors = other.verify::<Poll>(&url).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+        assert_eq!(errors[0].key.as_deref(), Some("fc:frame:image"));
+
+        let forged = signer()
+            .url(&Poll { question: "Tabs or spaces?".to_string(), votes: vec![100, 0] })
+            .unwrap();
+        let forged_params = forged.split("p=").nth(1).unwrap().split('&').next().unwrap();
+        let original_params = url.split("p=").nth(1).unwrap().split('&').next().unwrap();
+        let tampered = url.replace(original_params, forged_params);
+        let errors = signer().verify::<Poll>(&tampered).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+
+        let undated = url.split('&').filter(|pair| !pair.starts_with("t=")).collect::<Vec<_>>();
+        let errors = signer().verify::<Poll>(&undated.join("&")).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+
+        // a signature only holds for the route it was made for
+        let moved = url.replace("/api/image?", "/api/avatar?");
+        let errors = signer().verify::<Poll>(&moved).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+        let path = url.trim_start_matches("https://example.com");
+        let errors = signer().verify::<Poll>(&format!("/v2{}", path)).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+
+        let errors = signer().verify::<Poll>("/api/image?theme=dark").unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidImageUrl);
+    }
+
+    #[test]
+    fn it_expires_urls_older_than_the_max_age() {
+        let signer = signer().with_max_age(Duration::minutes(5));
+
+        let fresh = signer.url_at(&poll(), Utc::now()).unwrap();
+        assert!(signer.verify::<Poll>(&fresh).is_ok());
+
+        let stale = signer.url_at(&poll(), Utc::now() - Duration::minutes(10)).unwrap();
+        let errors = signer.verify::<Poll>(&stale).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidImageUrl);
+
+        let undated = signer.url(&poll()).unwrap();
+        assert!(signer.verify::<Poll>(&undated).is_err());
+    }
+
+    #[test]
+    fn it_reports_params_of_the_wrong_shape() {
+        let url = signer().url(&vec!["not", "a", "poll"]).unwrap();
+        let errors = signer().verify::<Poll>(&url).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidImageUrl);
+    }
+}
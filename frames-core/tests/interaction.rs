@@ -0,0 +1,76 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use frames_core::{
+        provider::{hub::HubClient, interaction::InteractionProvider},
+        testing::{http::HttpStub, interaction::FixtureInteractions, signer::TestSigner},
+        types::action::{ActionCastId, ValidatedFrameAction},
+    };
+
+    const CAST_HASH: &str = "0x7363f449bfb0e7f01c5a1cc0054768ed5146abc0";
+    const MESSAGE: &str =
+        r#"{"data":{"type":"MESSAGE_TYPE_REACTION_ADD","fid":2,"timestamp":1},"hash":"0x01"}"#;
+
+    async fn action() -> ValidatedFrameAction {
+        let signer = TestSigner::new(2);
+        let payload = signer.action("https://example.com/frame").cast_id(1795, CAST_HASH).payload();
+        payload.verify(&signer).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_checks_reactions_and_follows_on_the_hub() {
+        let hub = HttpStub::start().await;
+        hub.route(
+            &format!(
+                "/v1/reactionById?fid=2&reaction_type=2&target_fid=1795&target_hash={}",
+                CAST_HASH
+            ),
+            200,
+            MESSAGE,
+        )
+        .route(
+            &format!(
+                "/v1/reactionById?fid=2&reaction_type=1&target_fid=1795&target_hash={}",
+                CAST_HASH
+            ),
+            400,
+            r#"{"errCode":"not_found","message":"reactionAdd not found"}"#,
+        )
+        .route("/v1/linkById?fid=2&target_fid=1795&link_type=follow", 200, MESSAGE);
+        let (hub_client, action) = (HubClient::new(&hub.url), action().await);
+        let cast_id = action.cast_id.as_ref().unwrap();
+
+        assert!(hub_client.has_recasted(action.fid, cast_id).await.unwrap());
+        assert!(!hub_client.has_liked(action.fid, cast_id).await.unwrap());
+        assert!(hub_client.follows(action.fid, 1795).await.unwrap());
+        assert!(!hub_client.follows(action.fid, 3).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn it_reports_hub_errors() {
+        let hub = HttpStub::start().await;
+        hub.route(
+            "/v1/linkById?fid=2&target_fid=1795&link_type=follow",
+            400,
+            r#"{"errCode":"bad_request.validation_failure","message":"fid is missing"}"#,
+        );
+
+        let error = HubClient::new(&hub.url).follows(2, 1795).await.unwrap_err();
+        assert_eq!(error.to_string(), "bad_request.validation_failure: fid is missing");
+    }
+
+    #[tokio::test]
+    async fn it_checks_interactions_from_fixtures() {
+        let action = action().await;
+        let cast_id = action.cast_id.clone().unwrap();
+        let interactions: Box<dyn InteractionProvider> = Box::new(
+            FixtureInteractions::new()
+                .with_like(2, &ActionCastId { fid: 1795, hash: CAST_HASH.to_uppercase() })
+                .with_follow(2, 1795),
+        );
+
+        assert!(interactions.has_liked(action.fid, &cast_id).await.unwrap());
+        assert!(!interactions.has_recasted(action.fid, &cast_id).await.unwrap());
+        assert!(interactions.follows(2, 1795).await.unwrap());
+        assert!(!interactions.follows(1795, 2).await.unwrap());
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(all(test, feature = "lens", feature = "testing"))]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use ethers::types::Address;
+    use frames_core::{
+        testing::lens::LensTestSigner,
+        types::{
+            action::Actor,
+            errors::ErrorCode,
+            lens::{LensDomain, LensFrameActionPayload},
+        },
+    };
+
+    #[tokio::test]
+    async fn it_verifies_lens_frame_actions() {
+        let signer = LensTestSigner::new(1);
+        let deadline = Utc.timestamp_opt(Utc::now().timestamp() + 300, 0).unwrap();
+        let json = signer
+            .action("https://example.com/frame")
+            .profile_id("0x05")
+            .pub_id("0x05-0x2a")
+            .button_index(3)
+            .input_text("gm")
+            .state("{\"step\":1}")
+            .action_response("0xabc")
+            .timestamp(Utc.timestamp_opt(1_710_000_000, 0).unwrap())
+            .deadline(deadline)
+            .json();
+
+        let payload: LensFrameActionPayload = serde_json::from_str(&json).unwrap();
+        let action = payload.verify(&signer.domain, &signer).await.unwrap();
+
+        assert_eq!(action.signer, format!("{:?}", signer.address()));
+        assert_eq!(
+            action.actor,
+            Actor::Lens { profile_id: "0x05".to_string(), pub_id: "0x05-0x2a".to_string() }
+        );
+        assert_eq!(action.url, "https://example.com/frame");
+        assert_eq!(action.button_index, 3);
+        assert_eq!(action.input_text.as_deref(), Some("gm"));
+        assert_eq!(action.state.as_deref(), Some("{\"step\":1}"));
+        assert_eq!(action.transaction_id.as_deref(), Some("0xabc"));
+        // the unix timestamp is not signed, actions are timestamped when verified instead
+        assert!(Utc::now() - action.timestamp < Duration::seconds(5));
+        assert_eq!(action.expires_at, Some(deadline));
+        assert!(action.message_hash.starts_with("0x") && action.message_hash.len() == 66);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_signers_not_allowed_to_act_for_the_profile() {
+        let signer = LensTestSigner::new(1);
+        let payload = signer.action("https://example.com").payload();
+        let errors = payload.verify(&signer.domain, &LensTestSigner::new(2)).await.unwrap_err();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnregisteredSigner);
+
+        // tampered data and other domains recover some other address
+        let mut payload = signer.action("https://example.com").payload();
+        payload.untrusted_data.button_index = 2;
+        let errors = payload.verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UnregisteredSigner);
+
+        let other_domain = LensDomain::new(80002, Address::repeat_byte(0x1e));
+        let payload = signer.action("https://example.com").payload();
+        let errors = payload.verify(&other_domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UnregisteredSigner);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_and_expired_signatures() {
+        let signer = LensTestSigner::new(1);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.trusted_data.message_bytes = "0x1234".to_string();
+        let errors = payload.verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+
+        let expired =
+            signer.action("https://example.com").deadline(Utc::now() - Duration::minutes(1));
+        let errors = expired.payload().verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::ExpiredAction);
+    }
+
+    #[tokio::test]
+    async fn it_reports_malformed_payloads() {
+        let signer = LensTestSigner::new(1);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.client_protocol = "xmtp@2024-02-09".to_string();
+        let errors = payload.verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.untrusted_data.profile_id = "not a number".to_string();
+        let errors = payload.verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.untrusted_data.deadline = None;
+        let errors = payload.verify(&signer.domain, &signer).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+    }
+
+    #[tokio::test]
+    async fn it_checks_profile_owners_and_delegated_executors_on_the_lens_hub() {
+        use ethers::{
+            abi::{encode, Token},
+            types::{Bytes, U256},
+            utils::id,
+        };
+        use frames_core::{
+            provider::lens::{LensHubProvider, LENS_HUB_ADDRESS},
+            testing::evm::{mock_middleware, Fixtures},
+        };
+
+        let (owner, executor, stranger) =
+            (Address::repeat_byte(1), Address::repeat_byte(2), Address::repeat_byte(3));
+        let call = |signature: &str, tokens: &[Token]| -> Bytes {
+            let mut data = id(signature).to_vec();
+            data.extend(encode(tokens));
+            data.into()
+        };
+        let is_delegated_executor = |executor: Address| {
+            call(
+                "isDelegatedExecutorApproved(uint256,address)",
+                &[Token::Uint(U256::from(5)), Token::Address(executor)],
+            )
+        };
+        let lens_hub: Address = LENS_HUB_ADDRESS.parse().unwrap();
+        let fixtures = Fixtures::new(137)
+            .with_call(
+                lens_hub,
+                call("ownerOf(uint256)", &[Token::Uint(U256::from(5))]),
+                encode(&[Token::Address(owner)]),
+            )
+            .with_call(lens_hub, is_delegated_executor(executor), encode(&[Token::Bool(true)]))
+            .with_call(lens_hub, is_delegated_executor(stranger), encode(&[Token::Bool(false)]));
+        let provider = LensHubProvider::new(mock_middleware(fixtures));
+
+        assert!(provider.is_profile_signer(U256::from(5), owner).await.unwrap());
+        assert!(provider.is_profile_signer(U256::from(5), executor).await.unwrap());
+        assert!(!provider.is_profile_signer(U256::from(5), stranger).await.unwrap());
+        assert!(provider.is_profile_signer(U256::from(6), owner).await.is_err());
+    }
+}
@@ -1,2 +1,25 @@
+mod action;
+mod actix;
+mod cast_action;
+mod chain;
+mod composer_action;
 mod frame;
+mod frame_action;
+mod gating;
+mod hub;
+mod image_url;
+mod interaction;
+mod lens;
+mod mint;
+mod open_frames;
+mod profile;
 mod provider;
+mod render;
+mod render_html;
+mod replay;
+mod router;
+mod simulation;
+mod testing;
+mod url;
+mod username;
+mod xmtp;
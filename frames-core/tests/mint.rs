@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use ethers::types::{Address, U256};
+    use frames_core::types::caip::Caip10Address;
+
+    #[test]
+    fn it_parses_caip10_addresses() {
+        let target: Caip10Address =
+            "eip155:7777777:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df:123".parse().unwrap();
+        assert_eq!(target.chain_id, 7777777);
+        assert_eq!(
+            target.address,
+            "0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df".parse::<Address>().unwrap()
+        );
+        assert_eq!(target.token_id, Some(U256::from(123)));
+        assert_eq!(
+            target.to_string(),
+            "eip155:7777777:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df:123"
+        );
+
+        let target: Caip10Address =
+            "eip155:8453:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df".parse().unwrap();
+        assert_eq!(target.token_id, None);
+    }
+
+    #[test]
+    fn it_rejects_invalid_caip10_addresses() {
+        for target in [
+            "0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df",
+            "solana:mainnet:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df",
+            "eip155:base:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df",
+            "eip155:8453:0x060f3edd",
+            "eip155:8453:0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df:one",
+        ] {
+            assert!(target.parse::<Caip10Address>().is_err(), "{}", target);
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    mod fixtures {
+        use ethers::{
+            abi::{self, Token},
+            types::{Address, Bytes},
+            utils::id,
+        };
+        use frames_core::{
+            provider::chain::ChainRegistry,
+            testing::evm::{mock_middleware, Fixtures, MockMiddleware},
+            types::{
+                button::FrameButton,
+                errors::ErrorCode,
+                frame::Frame,
+                image::{AspectRatio, FrameImage},
+            },
+        };
+
+        const ERC721: &str = "0x060f3edd18c47f59bd23d063bbeb9aa4a8fec6df";
+        const ERC1155: &str = "0x0000000000000000000000000000000000001155";
+        const ERC20: &str = "0x0000000000000000000000000000000000000020";
+
+        fn supports_interface(interface_id: [u8; 4]) -> Bytes {
+            let args = abi::encode(&[Token::FixedBytes(interface_id.to_vec())]);
+            [&id("supportsInterface(bytes4)")[..], &args].concat().into()
+        }
+
+        fn bool(value: bool) -> Bytes {
+            abi::encode(&[Token::Bool(value)]).into()
+        }
+
+        fn chains() -> ChainRegistry<MockMiddleware> {
+            let (erc721, erc1155, erc20): (Address, Address, Address) =
+                (ERC721.parse().unwrap(), ERC1155.parse().unwrap(), ERC20.parse().unwrap());
+            let fixtures = Fixtures::new(8453)
+                .with_code(erc721, vec![0x60, 0x80])
+                .with_code(erc1155, vec![0x60, 0x80])
+                .with_code(erc20, vec![0x60, 0x80])
+                .with_call(erc721, supports_interface([0x80, 0xac, 0x58, 0xcd]), bool(true))
+                .with_call(erc1155, supports_interface([0x80, 0xac, 0x58, 0xcd]), bool(false))
+                .with_call(erc1155, supports_interface([0xd9, 0xb6, 0x7a, 0x26]), bool(true))
+                .with_call(erc20, supports_interface([0x80, 0xac, 0x58, 0xcd]), bool(false))
+                .with_call(erc20, supports_interface([0xd9, 0xb6, 0x7a, 0x26]), bool(false));
+
+            ChainRegistry::new().with_chain(8453, mock_middleware(fixtures))
+        }
+
+        fn mint(target: &str) -> FrameButton {
+            FrameButton {
+                id: 1,
+                label: "Mint".to_string(),
+                action: Some("mint".to_string()),
+                target: Some(target.to_string()),
+            }
+        }
+
+        async fn error_code(target: &str) -> ErrorCode {
+            let mut errors = mint(target).validate_mint(&chains()).await.unwrap_err().errors;
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].key, Some("fc:frame:button:1:target".to_string()));
+            errors.remove(0).code
+        }
+
+        #[tokio::test]
+        async fn it_accepts_erc721_and_erc1155_mint_targets() {
+            let chains = chains();
+
+            assert!(mint(&format!("eip155:8453:{}", ERC721)).validate_mint(&chains).await.is_ok());
+            assert!(mint(&format!("eip155:8453:{}:1", ERC1155))
+                .validate_mint(&chains)
+                .await
+                .is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_reports_invalid_mint_targets() {
+            assert_eq!(error_code(ERC721).await, ErrorCode::InvalidMintTarget);
+            assert_eq!(
+                error_code(&format!("eip155:1:{}", ERC721)).await,
+                ErrorCode::UnsupportedChain
+            );
+            assert_eq!(
+                error_code("eip155:8453:0x00000000000000000000000000000000000000ff").await,
+                ErrorCode::MissingContract
+            );
+            assert_eq!(
+                error_code(&format!("eip155:8453:{}", ERC20)).await,
+                ErrorCode::UnsupportedTokenStandard
+            );
+        }
+
+        #[tokio::test]
+        async fn it_reports_failed_chain_queries() {
+            let chains = ChainRegistry::new().with_chain(
+                8453,
+                mock_middleware(
+                    Fixtures::new(8453).with_code(ERC721.parse().unwrap(), vec![0x60, 0x80]),
+                ),
+            );
+
+            let errors = mint(&format!("eip155:8453:{}", ERC721))
+                .validate_mint(&chains)
+                .await
+                .unwrap_err()
+                .errors;
+            assert_eq!(errors[0].code, ErrorCode::FailedToQueryChain);
+        }
+
+        #[tokio::test]
+        async fn it_merges_mint_errors_into_the_frame_report() {
+            let frame = Frame {
+                image: FrameImage {
+                    url: "https://example.com/image.png".to_string(),
+                    aspect_ratio: AspectRatio::None,
+                },
+                buttons: vec![
+                    mint(&format!("eip155:8453:{}", ERC721)),
+                    FrameButton { id: 2, ..mint(&format!("eip155:10:{}", ERC721)) },
+                    FrameButton { id: 3, action: Some("swap".to_string()), ..mint(ERC721) },
+                ],
+                ..Default::default()
+            };
+
+            let errors = frame.validate_with_chains(&chains()).await.unwrap_err().errors;
+            let codes: Vec<&ErrorCode> = errors.iter().map(|error| &error.code).collect();
+            assert_eq!(codes, vec![&ErrorCode::InvalidButtonAction, &ErrorCode::UnsupportedChain]);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use frames_core::{
+        serializers::HtmlSerializer,
+        types::{
+            button::FrameButton,
+            frame::{AcceptedProtocol, Frame, OpenFrames},
+            image::{AspectRatio, FrameImage},
+        },
+    };
+
+    fn frame() -> Frame {
+        Frame {
+            title: "Poll".to_string(),
+            version: "vNext".to_string(),
+            image: FrameImage {
+                url: "https://example.com/poll.png".to_string(),
+                aspect_ratio: AspectRatio::OneToOne,
+            },
+            post_url: Some("https://example.com/vote".to_string()),
+            buttons: vec![FrameButton {
+                id: 1,
+                label: "Yes".to_string(),
+                action: Some("post".to_string()),
+                target: None,
+            }],
+            input_text: None,
+            open_frames: Some(
+                OpenFrames::new("vNext").accept("xmtp", "2024-02-01").accept("lens", "1.0.0"),
+            ),
+        }
+    }
+
+    #[test]
+    fn it_emits_fc_and_of_tags_from_one_definition() {
+        let html = frame().to_html();
+
+        assert!(html.starts_with(r#"<title>Poll</title><meta name="fc:frame" content="vNext" />"#));
+        assert!(html.contains(r#"<meta name="fc:frame:button:1" content="Yes" />"#));
+        assert!(html.ends_with(
+            r#"<meta name="of:version" content="vNext" /><meta name="of:accepts:xmtp" content="2024-02-01" /><meta name="of:accepts:lens" content="1.0.0" /><meta name="of:image" content="https://example.com/poll.png" /><meta name="of:image:aspect_ratio" content="1:1" /><meta name="of:button:1" content="Yes" /><meta name="of:button:1:action" content="post" /><meta name="of:post_url" content="https://example.com/vote" />"#
+        ));
+
+        let farcaster_only = Frame { open_frames: None, ..frame() };
+        assert!(!farcaster_only.to_html().contains("of:"));
+    }
+
+    #[test]
+    fn it_round_trips_open_frames() {
+        let mut parsed = Frame::new();
+        parsed.from_html(&frame().to_html()).unwrap();
+
+        assert_eq!(parsed, frame());
+        assert_eq!(parsed.protocols(), vec!["farcaster", "xmtp", "lens"]);
+        assert!(parsed.accepts("xmtp"));
+        assert!(!parsed.accepts("bluesky"));
+    }
+
+    #[test]
+    fn it_parses_frames_with_only_of_tags() {
+        let html = r#"
+            <title>XMTP only</title>
+            <meta property="of:version" content="vNext" />
+            <meta property="of:accepts:xmtp" content="2024-02-01" />
+            <meta property="of:image" content="https://example.com/image.png" />
+            <meta property="of:image:aspect_ratio" content="1.91:1" />
+            <meta property="of:input:text" content="Say something" />
+            <meta property="of:button:1" content="Open" />
+            <meta property="of:button:1:action" content="link" />
+            <meta property="of:button:1:target" content="https://example.com" />
+            <meta property="of:post_url" content="https://example.com/post" />
+        "#;
+
+        let mut frame = Frame::new();
+        frame.from_html(html).unwrap();
+
+        assert_eq!(frame.version, "");
+        assert_eq!(
+            frame.open_frames.as_ref().unwrap().accepts,
+            vec![AcceptedProtocol { id: "xmtp".to_string(), version: "2024-02-01".to_string() }]
+        );
+        assert_eq!(frame.protocols(), vec!["xmtp"]);
+        assert!(!frame.accepts("farcaster"));
+        assert_eq!(frame.image.aspect_ratio, AspectRatio::OnePointNineToOne);
+        assert_eq!(frame.input_text.as_deref(), Some("Say something"));
+        assert_eq!(frame.buttons[0].action.as_deref(), Some("link"));
+        assert_eq!(frame.buttons[0].target.as_deref(), Some("https://example.com"));
+        assert_eq!(frame.post_url.as_deref(), Some("https://example.com/post"));
+    }
+
+    #[test]
+    fn it_prefers_fc_tags_over_of_tags() {
+        let html = r#"
+            <title>Both</title>
+            <meta name="fc:frame" content="vNext" />
+            <meta name="fc:frame:image" content="https://example.com/farcaster.png" />
+            <meta name="of:version" content="vNext" />
+            <meta name="of:accepts:xmtp" content="2024-02-01" />
+            <meta name="of:image" content="https://example.com/xmtp.png" />
+            <meta name="of:button:1" content="Open Frames" />
+        "#;
+
+        let mut frame = Frame::new();
+        frame.from_html(html).unwrap();
+
+        assert_eq!(frame.image.url, "https://example.com/farcaster.png");
+        assert_eq!(frame.buttons[0].label, "Open Frames");
+        assert_eq!(frame.protocols(), vec!["farcaster", "xmtp"]);
+    }
+}
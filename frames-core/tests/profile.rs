@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use frames_core::types::profile::UserProfile;
+
+    #[test]
+    fn it_falls_back_to_the_username_and_fid_for_names() {
+        let mut profile = UserProfile { fid: 3, ..Default::default() };
+        assert_eq!(profile.name(), "!3");
+
+        profile.username = Some("dwr".to_string());
+        assert_eq!(profile.name(), "dwr");
+
+        profile.display_name = Some("Dan Romero".to_string());
+        assert_eq!(profile.name(), "Dan Romero");
+    }
+
+    #[cfg(feature = "testing")]
+    mod hub {
+        use std::time::Duration;
+
+        use frames_core::{
+            provider::{
+                hub::HubClient,
+                profile::{CachedProfiles, ProfileProvider},
+            },
+            testing::{http::HttpStub, profile::FixtureProfiles},
+            types::profile::UserProfile,
+        };
+
+        fn user_data(data_type: &str, value: &str, timestamp: u64) -> String {
+            format!(
+                r#"{{"data":{{"type":"MESSAGE_TYPE_USER_DATA_ADD","fid":3,"timestamp":{},"userDataBody":{{"type":"USER_DATA_TYPE_{}","value":"{}"}}}},"hash":"0x01"}}"#,
+                timestamp, data_type, value
+            )
+        }
+
+        async fn hub() -> HttpStub {
+            let hub = HttpStub::start().await;
+            let messages = [
+                user_data("DISPLAY", "Dan", 2),
+                user_data("PFP", "https://i.imgur.com/dwr.png", 1),
+                user_data("DISPLAY", "Dan Romero", 3),
+                user_data("USERNAME", "dwr", 1),
+                user_data("BIO", "", 1),
+            ];
+            hub.route(
+                "/v1/userDataByFid?fid=3",
+                200,
+                &format!(r#"{{"messages":[{}]}}"#, messages.join(",")),
+            )
+            .route(
+                "/v1/verificationsByFid?fid=3",
+                200,
+                r#"{"messages":[{"data":{"type":"MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS","fid":3,"timestamp":1,"verificationAddAddressBody":{"address":"0xd7029bdea1c17493893aafe29aad69ef892b8ff2","protocol":"PROTOCOL_ETHEREUM"}},"hash":"0x02"}]}"#,
+            );
+            hub
+        }
+
+        #[tokio::test]
+        async fn it_builds_profiles_from_user_data_messages() {
+            let hub = hub().await;
+
+            let profile = HubClient::new(&hub.url).get_user_profile(3).await.unwrap();
+            assert_eq!(
+                profile,
+                UserProfile {
+                    fid: 3,
+                    username: Some("dwr".to_string()),
+                    display_name: Some("Dan Romero".to_string()),
+                    pfp_url: Some("https://i.imgur.com/dwr.png".to_string()),
+                    bio: None,
+                    url: None,
+                    verified_addresses: vec!["0xd7029bdea1c17493893aafe29aad69ef892b8ff2"
+                        .parse()
+                        .unwrap()],
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn it_caches_profiles() {
+            let hub = hub().await;
+            let profiles = CachedProfiles::new(HubClient::new(&hub.url), Duration::from_secs(60));
+
+            let first = profiles.get_user_profile(3).await.unwrap();
+            let second = profiles.get_user_profile(3).await.unwrap();
+            assert_eq!(first, second);
+            assert_eq!(hub.requests().len(), 2);
+
+            profiles.clear_cache();
+            profiles.get_user_profile(3).await.unwrap();
+            assert_eq!(hub.requests().len(), 4);
+        }
+
+        #[tokio::test]
+        async fn it_serves_profiles_from_fixtures() {
+            let profile =
+                UserProfile { fid: 3, username: Some("dwr".to_string()), ..Default::default() };
+            let profiles: Box<dyn ProfileProvider> =
+                Box::new(FixtureProfiles::new().with_profile(profile.clone()));
+
+            assert_eq!(profiles.get_user_profile(3).await.unwrap(), profile);
+            assert!(profiles.get_user_profile(4).await.is_err());
+        }
+    }
+}
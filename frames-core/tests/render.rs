@@ -0,0 +1,173 @@
+#[cfg(all(test, feature = "image"))]
+mod tests {
+    use frames_core::{
+        render::{Color, Element, Fonts, ImageLayout, TextAlign},
+        types::{errors::ErrorCode, image::AspectRatio},
+    };
+
+    fn text(x: f32, text: &str, align: TextAlign) -> Element {
+        Element::Text {
+            x,
+            y: 0.0,
+            text: text.to_string(),
+            size: 100.0,
+            color: Color::BLACK,
+            font: None,
+            align,
+            max_width: None,
+        }
+    }
+
+    fn has_ink(layout: &ImageLayout, x: std::ops::Range<u32>, y: std::ops::Range<u32>) -> bool {
+        let pixmap = layout.render(&Fonts::default()).unwrap();
+        x.flat_map(|x| y.clone().map(move |y| (x, y)))
+            .any(|(x, y)| pixmap.pixel(x, y).unwrap().red() < 128)
+    }
+
+    #[test]
+    fn it_sizes_images_by_aspect_ratio() {
+        assert_eq!(ImageLayout::new(AspectRatio::OnePointNineToOne).size().unwrap(), (1200, 628));
+        assert_eq!(ImageLayout::new(AspectRatio::None).size().unwrap(), (1200, 628));
+        assert_eq!(ImageLayout::new(AspectRatio::OneToOne).width(600).size().unwrap(), (600, 600));
+
+        let errors = ImageLayout::new(AspectRatio::Error).size().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::FailedToRenderImage);
+    }
+
+    #[test]
+    fn it_draws_rectangles_over_the_background() {
+        let layout = ImageLayout::new(AspectRatio::OneToOne)
+            .width(100)
+            .background(Color::rgb(0, 0, 255))
+            .element(Element::Rect {
+                x: 10.0,
+                y: 10.0,
+                width: 30.0,
+                height: 30.0,
+                color: Color::rgb(255, 0, 0),
+                radius: 0.0,
+            });
+        let pixmap = layout.render(&Fonts::default()).unwrap();
+
+        let inside = pixmap.pixel(20, 20).unwrap();
+        assert_eq!((inside.red(), inside.green(), inside.blue()), (255, 0, 0));
+        let outside = pixmap.pixel(60, 60).unwrap();
+        assert_eq!((outside.red(), outside.green(), outside.blue()), (0, 0, 255));
+    }
+
+    #[test]
+    fn it_draws_aligned_text_with_the_bundled_fonts() {
+        let left =
+            ImageLayout::new(AspectRatio::OneToOne).element(text(0.0, "Hi", TextAlign::Left));
+        assert!(has_ink(&left, 0..200, 0..120));
+        assert!(!has_ink(&left, 400..1200, 0..1200));
+
+        let center =
+            ImageLayout::new(AspectRatio::OneToOne).element(text(600.0, "Hi", TextAlign::Center));
+        assert!(has_ink(&center, 500..700, 0..120));
+        assert!(!has_ink(&center, 0..450, 0..1200));
+
+        let right =
+            ImageLayout::new(AspectRatio::OneToOne).element(text(1200.0, "Hi", TextAlign::Right));
+        assert!(has_ink(&right, 1000..1200, 0..120));
+        assert!(!has_ink(&right, 0..900, 0..1200));
+    }
+
+    #[test]
+    fn it_wraps_text_to_the_max_width() {
+        let layout = ImageLayout::new(AspectRatio::OneToOne).element(Element::Text {
+            x: 0.0,
+            y: 0.0,
+            text: "Votes are in".to_string(),
+            size: 100.0,
+            color: Color::BLACK,
+            font: None,
+            align: TextAlign::Left,
+            max_width: Some(400.0),
+        });
+
+        assert!(has_ink(&layout, 0..400, 120..240));
+        assert!(!has_ink(&layout, 450..1200, 0..1200));
+    }
+
+    #[test]
+    fn it_embeds_png_images() {
+        let red = ImageLayout::new(AspectRatio::OneToOne)
+            .width(10)
+            .background(Color::rgb(255, 0, 0))
+            .render_png(&Fonts::default())
+            .unwrap();
+        let layout = ImageLayout::new(AspectRatio::OneToOne).width(100).element(Element::Image {
+            x: 50.0,
+            y: 50.0,
+            width: 50.0,
+            height: 50.0,
+            png: red,
+        });
+        let pixmap = layout.render(&Fonts::default()).unwrap();
+
+        let pixel = pixmap.pixel(75, 75).unwrap();
+        assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (255, 0, 0));
+        assert_eq!(pixmap.pixel(25, 25).unwrap().green(), 255);
+
+        let invalid = ImageLayout::new(AspectRatio::OneToOne).element(Element::Image {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+            png: vec![1, 2, 3],
+        });
+        assert!(invalid.render_png(&Fonts::default()).is_err());
+    }
+
+    #[test]
+    fn it_serializes_images_as_base64() {
+        let image = Element::Image { x: 0.0, y: 0.0, width: 1.0, height: 1.0, png: vec![1, 2, 3] };
+
+        let json = serde_json::to_value(&image).unwrap();
+        assert_eq!(json["png"], "AQID");
+        assert_eq!(serde_json::from_value::<Element>(json).unwrap(), image);
+    }
+
+    #[test]
+    fn it_renders_layouts_into_valid_frame_images() {
+        let layout: ImageLayout = serde_json::from_str(
+            r##"{
+                "aspectRatio": "1.91:1",
+                "background": "#111111",
+                "elements": [
+                    { "type": "rect", "x": 40, "y": 40, "width": 400, "height": 80, "color": "#ff000080", "radius": 16 },
+                    { "type": "text", "x": 600, "y": 260, "text": "42 votes", "size": 64, "color": "#ffffff", "align": "center", "font": "DejaVu Sans Bold", "maxWidth": 800 }
+                ]
+            }"##,
+        )
+        .unwrap();
+
+        let image = layout.to_frame_image(&Fonts::default()).unwrap();
+        assert!(image.url.starts_with("data:image/png;base64,iVBORw0KGgo"));
+        assert_eq!(image.aspect_ratio, AspectRatio::OnePointNineToOne);
+        assert!(image.validate().is_ok());
+    }
+
+    #[test]
+    fn it_reports_unknown_and_invalid_fonts() {
+        let layout = ImageLayout::new(AspectRatio::OneToOne).element(Element::Text {
+            x: 0.0,
+            y: 0.0,
+            text: "Hi".to_string(),
+            size: 100.0,
+            color: Color::BLACK,
+            font: Some("Comic Sans".to_string()),
+            align: TextAlign::Left,
+            max_width: None,
+        });
+        assert!(layout.render_png(&Fonts::default()).is_err());
+        assert!(layout.render_png(&Fonts::empty()).is_err());
+
+        assert!(Fonts::empty().with_font("Broken", vec![0; 16]).is_err());
+        let fonts = Fonts::empty()
+            .with_font("Comic Sans", include_bytes!("../assets/fonts/DejaVuSans.ttf").to_vec())
+            .unwrap();
+        assert!(layout.render_png(&fonts).is_ok());
+    }
+}
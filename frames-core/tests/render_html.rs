@@ -0,0 +1,177 @@
+#[cfg(all(test, feature = "html"))]
+mod tests {
+    use frames_core::{
+        render::{Color, Fonts, HtmlImage, ImageLayout},
+        types::{
+            errors::ErrorCode,
+            image::{AspectRatio, FrameImage},
+        },
+    };
+    use tiny_skia::Pixmap;
+
+    fn render(html: &str) -> Pixmap {
+        HtmlImage::new(html, AspectRatio::OneToOne).width(100).render(&Fonts::default()).unwrap()
+    }
+
+    fn rgb(pixmap: &Pixmap, x: u32, y: u32) -> (u8, u8, u8) {
+        let pixel = pixmap.pixel(x, y).unwrap();
+        (pixel.red(), pixel.green(), pixel.blue())
+    }
+
+    fn has_ink(pixmap: &Pixmap, x: std::ops::Range<u32>, y: std::ops::Range<u32>) -> bool {
+        x.flat_map(|x| y.clone().map(move |y| (x, y)))
+            .any(|(x, y)| pixmap.pixel(x, y).unwrap().red() < 128)
+    }
+
+    #[test]
+    fn it_sizes_the_root_by_aspect_ratio() {
+        let fonts = Fonts::default();
+        let wide = HtmlImage::new("<div></div>", AspectRatio::OnePointNineToOne);
+        assert_eq!(wide.render(&fonts).unwrap().height(), 628);
+
+        let image = FrameImage { url: String::new(), aspect_ratio: AspectRatio::OneToOne };
+        let square = HtmlImage::for_image("<div></div>", &image).width(300);
+        assert_eq!(square.render(&fonts).unwrap().height(), 300);
+
+        let errors = HtmlImage::new("", AspectRatio::Error).render(&fonts).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::FailedToRenderImage);
+    }
+
+    #[test]
+    fn it_lays_out_flex_containers() {
+        let pixmap = render(
+            r#"<div style="width: 100%; height: 100%; background: #0000ff;
+                           justify-content: center; align-items: center">
+                 <div style="width: 20px; height: 20px; background: rgb(255, 0, 0)"></div>
+               </div>"#,
+        );
+        assert_eq!(rgb(&pixmap, 50, 50), (255, 0, 0));
+        assert_eq!(rgb(&pixmap, 35, 50), (0, 0, 255));
+
+        let pixmap = render(
+            r#"<div style="display: flex; flex-direction: column; width: 100%; height: 100%">
+                 <div style="flex: 1; background: red"></div>
+                 <div style="flex-grow: 3; background: blue; padding: 5px 10px"></div>
+                 <div style="display: none; flex: 100; background: black"></div>
+               </div>"#,
+        );
+        assert_eq!(rgb(&pixmap, 50, 10), (255, 0, 0));
+        assert_eq!(rgb(&pixmap, 50, 30), (0, 0, 255));
+        assert_eq!(rgb(&pixmap, 50, 95), (0, 0, 255));
+    }
+
+    #[test]
+    fn it_positions_elements_absolutely() {
+        let pixmap = render(
+            r#"<div style="width: 100%; height: 100%">
+                 <div style="position: absolute; right: 0; bottom: 0; width: 10px; height: 10px;
+                             background: #f00"></div>
+               </div>"#,
+        );
+        assert_eq!(rgb(&pixmap, 95, 95), (255, 0, 0));
+        assert_eq!(rgb(&pixmap, 5, 5), (255, 255, 255));
+    }
+
+    #[test]
+    fn it_draws_text_with_inherited_styles() {
+        let html = |align: &str| {
+            format!(
+                r#"<div style="width: 100%; font-size: 200px; text-align: {}">
+                     <h2 style="width: 100%">I</h2>
+                   </div>"#,
+                align
+            )
+        };
+        let fonts = Fonts::default();
+        let render = |align: &str| {
+            HtmlImage::new(html(align), AspectRatio::OneToOne).render(&fonts).unwrap()
+        };
+
+        let left = render("left");
+        assert!(has_ink(&left, 0..150, 0..360));
+        assert!(!has_ink(&left, 300..1200, 0..1200));
+
+        let right = render("right");
+        assert!(has_ink(&right, 1050..1200, 0..360));
+        assert!(!has_ink(&right, 0..900, 0..1200));
+
+        let white = render("center; color: white");
+        assert!(!has_ink(&white, 0..1200, 0..1200));
+    }
+
+    #[test]
+    fn it_wraps_text_to_the_container_width() {
+        let fonts = Fonts::default();
+        let wrapped = HtmlImage::new(
+            r#"<div style="width: 400px; font-size: 100px">one two three</div>"#,
+            AspectRatio::OneToOne,
+        )
+        .render(&fonts)
+        .unwrap();
+        assert!(has_ink(&wrapped, 0..400, 130..230));
+        assert!(!has_ink(&wrapped, 420..1200, 0..1200));
+    }
+
+    #[test]
+    fn it_embeds_data_uri_images() {
+        let red = ImageLayout::new(AspectRatio::OneToOne)
+            .width(10)
+            .background(Color::rgb(255, 0, 0))
+            .to_data_uri(&Fonts::default())
+            .unwrap();
+        let pixmap = render(&format!(
+            r#"<div style="padding: 50px; align-items: flex-start"><img src="{}" width="40"></div>"#,
+            red
+        ));
+        assert_eq!(rgb(&pixmap, 70, 70), (255, 0, 0));
+        assert_eq!(rgb(&pixmap, 95, 95), (255, 255, 255));
+        assert_eq!(rgb(&pixmap, 25, 25), (255, 255, 255));
+
+        let remote =
+            HtmlImage::new(r#"<img src="https://example.com/a.png">"#, AspectRatio::OneToOne);
+        let errors = remote.render(&Fonts::default()).unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::FailedToRenderImage);
+    }
+
+    #[test]
+    fn it_reports_invalid_css_values() {
+        let fonts = Fonts::default();
+        for style in
+            ["width: wide", "background: #12", "justify-content: middle", "padding: 1 2 3 4 5"]
+        {
+            let html = format!(r#"<div style="{}"></div>"#, style);
+            let errors = HtmlImage::new(html, AspectRatio::OneToOne).to_svg(&fonts).unwrap_err();
+            assert_eq!(errors.errors[0].code, ErrorCode::FailedToRenderImage, "{}", style);
+            assert_eq!(errors.errors[0].key.as_deref(), Some("fc:frame:image"));
+        }
+
+        let unknown = HtmlImage::new(
+            r#"<div style="box-shadow: 0 0 1px red; font-family: Missing">text</div>"#,
+            AspectRatio::OneToOne,
+        );
+        assert!(unknown.to_svg(&fonts).is_err());
+        let ignored = HtmlImage::new(
+            r#"<div style="box-shadow: 0 0 1px red">text</div>"#,
+            AspectRatio::OneToOne,
+        );
+        assert!(ignored.to_svg(&fonts).is_ok());
+    }
+
+    #[test]
+    fn it_renders_html_into_valid_frame_images() {
+        let image = HtmlImage::new(
+            r#"<div style="width: 100%; height: 100%; background: #111; color: white;
+                           align-items: center; justify-content: center; border-radius: 24px;
+                           border: 4px solid #fff; opacity: 0.9">
+                 <h1>Poll &amp; results</h1>
+               </div>"#,
+            AspectRatio::OnePointNineToOne,
+        )
+        .to_frame_image(&Fonts::default())
+        .unwrap();
+
+        assert!(image.url.starts_with("data:image/png;base64,iVBORw0KGgo"));
+        assert_eq!(image.aspect_ratio, AspectRatio::OnePointNineToOne);
+        assert!(image.validate().is_ok());
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::{num::NonZeroUsize, sync::Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Duration, Utc};
+    use frames_core::{
+        testing::signer::TestSigner,
+        types::{action::FrameAction, errors::ErrorCode},
+        validators::replay::{MemoryReplayStore, ReplayGuard, ReplayStore},
+    };
+
+    fn store(capacity: usize) -> MemoryReplayStore {
+        MemoryReplayStore::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_replayed_actions() {
+        let guard = ReplayGuard::new(store(16));
+        let signer = TestSigner::new(1);
+        let action = signer.action("https://example.com").payload().verify(&signer).await.unwrap();
+
+        assert!(guard.check(&action).await.is_ok());
+        let errors = guard.check(&action).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::ReplayedAction);
+
+        let other = signer.action("https://example.com").button_index(2).payload();
+        assert!(guard.check(&other.verify(&signer).await.unwrap()).await.is_ok());
+
+        let converted = FrameAction::from(action);
+        let errors = guard.check_action(&converted).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::ReplayedAction);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_actions_outside_the_window() {
+        let guard = ReplayGuard::new(store(16))
+            .with_max_age(Duration::minutes(5))
+            .with_max_skew(Duration::seconds(30));
+        let signer = TestSigner::new(1);
+        let signed_at = |offset: Duration| {
+            signer.action("https://example.com").timestamp(Utc::now() + offset).payload()
+        };
+
+        let stale = signed_at(Duration::minutes(-6)).verify(&signer).await.unwrap();
+        let errors = guard.check(&stale).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::StaleAction);
+
+        let future = signed_at(Duration::minutes(2)).verify(&signer).await.unwrap();
+        let errors = guard.check(&future).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::StaleAction);
+
+        let recent = signed_at(Duration::minutes(-4)).verify(&signer).await.unwrap();
+        assert!(guard.check(&recent).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_most_recent_hashes_and_forgets_expired_ones() {
+        let store = store(2);
+        let later = Utc::now() + Duration::minutes(1);
+
+        assert!(store.insert("0x01", later).await.unwrap());
+        assert!(store.insert("0x02", later).await.unwrap());
+        assert!(!store.insert("0x01", later).await.unwrap());
+        // evicts 0x02, the least recently used
+        assert!(store.insert("0x03", later).await.unwrap());
+        assert_eq!(store.len(), 2);
+        assert!(store.insert("0x02", later).await.unwrap());
+
+        let expired = Utc::now() - Duration::seconds(1);
+        assert!(store.insert("0x04", expired).await.unwrap());
+        assert!(store.insert("0x04", later).await.unwrap());
+    }
+
+    struct UnavailableStore;
+
+    #[async_trait]
+    impl ReplayStore for UnavailableStore {
+        async fn insert(
+            &self,
+            _: &str,
+            _: DateTime<Utc>,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            Err("connection refused".into())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingStore {
+        expiries: Mutex<Vec<DateTime<Utc>>>,
+    }
+
+    #[async_trait]
+    impl ReplayStore for RecordingStore {
+        async fn insert(
+            &self,
+            _: &str,
+            expires_at: DateTime<Utc>,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            self.expiries.lock().unwrap().push(expires_at);
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_remembers_actions_until_their_signed_deadline() {
+        let guard = ReplayGuard::new(RecordingStore::default()).with_max_age(Duration::minutes(5));
+        let signer = TestSigner::new(1);
+        let action = signer.action("https://example.com").payload().verify(&signer).await.unwrap();
+        let mut action = FrameAction::from(action);
+
+        let deadline = Utc::now() + Duration::hours(1);
+        action.expires_at = Some(deadline);
+        assert!(guard.check_action(&action).await.is_ok());
+
+        action.expires_at = Some(Utc::now());
+        assert!(guard.check_action(&action).await.is_ok());
+
+        let store = guard.store().expiries.lock().unwrap();
+        assert_eq!(store[0], deadline);
+        assert_eq!(store[1], action.timestamp + Duration::minutes(5));
+    }
+
+    #[tokio::test]
+    async fn it_fails_closed_when_the_store_is_unavailable() {
+        let guard = ReplayGuard::new(UnavailableStore);
+        let signer = TestSigner::new(1);
+        let action = signer.action("https://example.com").payload().verify(&signer).await.unwrap();
+
+        let errors = guard.check(&action).await.unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::FailedToCheckReplay);
+        assert_eq!(errors[0].description, "connection refused");
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use frames_core::{
+        router::{FrameRouter, NextFrame},
+        types::{
+            action::ValidatedFrameAction,
+            button::FrameButton,
+            errors::ErrorCode,
+            frame::Frame,
+            image::{AspectRatio, FrameImage},
+        },
+    };
+
+    fn frame(image: &str, labels: &[&str]) -> Frame {
+        Frame {
+            title: "Quiz".to_string(),
+            version: "vNext".to_string(),
+            image: FrameImage { url: image.to_string(), aspect_ratio: AspectRatio::None },
+            post_url: None,
+            buttons: labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| FrameButton {
+                    id: i + 1,
+                    label: label.to_string(),
+                    action: None,
+                    target: None,
+                })
+                .collect(),
+            input_text: None,
+            open_frames: None,
+        }
+    }
+
+    fn action(button_index: u32) -> ValidatedFrameAction {
+        ValidatedFrameAction {
+            fid: 2,
+            url: "https://example.com/frames".to_string(),
+            button_index,
+            input_text: None,
+            state: None,
+            cast_id: None,
+            transaction_id: None,
+            address: None,
+            timestamp: Utc::now(),
+            network: 1,
+            message_hash: "0x00".to_string(),
+            signer: "0x00".to_string(),
+        }
+    }
+
+    fn router() -> FrameRouter {
+        FrameRouter::new("https://example.com/frames")
+            .page("start", frame("https://example.com/start.png", &["Yes", "No"]))
+            .page("right", frame("https://example.com/right.png", &["Again"]))
+            .handler("start", 1, |_| Ok(NextFrame::Page("right".to_string())))
+            .handler("start", 2, |action| {
+                Ok(NextFrame::Frame {
+                    page: "start".to_string(),
+                    frame: frame(
+                        &format!("https://example.com/wrong/{}.png", action.fid),
+                        &["Retry"],
+                    ),
+                })
+            })
+            .handler("right", 1, |_| Ok(NextFrame::Page("start".to_string())))
+    }
+
+    #[test]
+    fn it_renders_pages_with_a_post_url_encoding_the_page() {
+        let frame = router().render("start").unwrap();
+
+        assert_eq!(frame.post_url, Some("https://example.com/frames?page=start".to_string()));
+        assert_eq!(FrameRouter::page_of(&frame.post_url.unwrap()), Some("start".to_string()));
+    }
+
+    #[test]
+    fn it_encodes_the_page_of_relative_base_urls() {
+        assert_eq!(FrameRouter::new("/frames").page_url("a b&c"), "/frames?page=a%20b%26c");
+        assert_eq!(
+            FrameRouter::new("/frames?theme=dark").page_url("start"),
+            "/frames?theme=dark&page=start"
+        );
+    }
+
+    #[test]
+    fn it_reads_back_the_page_of_absolute_and_relative_urls() {
+        let base_urls = [
+            "https://example.com/frames",
+            "https://example.com/frames?theme=dark",
+            "/frames",
+            "/frames?theme=dark",
+        ];
+        for base_url in base_urls {
+            let router = FrameRouter::new(base_url);
+            for page in ["start", "a b&c", "ünïcode/+?"] {
+                assert_eq!(
+                    FrameRouter::page_of(&router.page_url(page)),
+                    Some(page.to_string()),
+                    "{} of {}",
+                    page,
+                    base_url
+                );
+            }
+        }
+        assert_eq!(FrameRouter::page_of("/frames?theme=dark"), None);
+    }
+
+    #[test]
+    fn it_dispatches_actions_to_the_page_handler() {
+        let router = router();
+
+        let next = router.dispatch("https://example.com/frames?page=start", &action(1)).unwrap();
+        assert_eq!(next.image.url, "https://example.com/right.png");
+        assert_eq!(next.post_url, Some("https://example.com/frames?page=right".to_string()));
+
+        let next = router.dispatch("https://example.com/frames?page=start", &action(2)).unwrap();
+        assert_eq!(next.image.url, "https://example.com/wrong/2.png");
+        assert_eq!(next.post_url, Some("https://example.com/frames?page=start".to_string()));
+    }
+
+    #[test]
+    fn it_returns_an_error_for_unknown_pages_and_buttons() {
+        let router = router();
+
+        let errors = router.dispatch("https://example.com/frames", &action(1)).err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnknownPage);
+
+        let errors =
+            router.dispatch("https://example.com/frames?page=right", &action(2)).err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnhandledButton);
+
+        let errors = router.render("missing").err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnknownPage);
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use ethers::types::{Address, Bytes, U256};
+    use frames_core::{provider::simulation::RevertReason, types::transaction::FrameTransaction};
+
+    #[test]
+    fn it_parses_frame_transactions() {
+        let transaction: FrameTransaction = serde_json::from_str(
+            r#"{
+                "chainId": "eip155:8453",
+                "method": "eth_sendTransaction",
+                "params": {
+                    "abi": [],
+                    "to": "0x00000000fcce7f938e7ae6d3c335bd6a1a7c593d",
+                    "data": "0x783a112b",
+                    "value": "984316556204476"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(transaction.chain_id(), Ok(8453));
+        let request = transaction.request(Address::from_low_u64_be(1)).unwrap();
+        assert_eq!(request.value, Some(U256::from(984316556204476u64)));
+        assert_eq!(request.data, Some(Bytes::from(vec![0x78, 0x3a, 0x11, 0x2b])));
+
+        let invalid = FrameTransaction {
+            params: frames_core::types::transaction::TransactionParams {
+                value: Some("0x10".to_string()),
+                ..transaction.params.clone()
+            },
+            ..transaction.clone()
+        };
+        assert!(invalid.request(Address::zero()).is_err());
+
+        let signature =
+            FrameTransaction { method: "eth_signTypedData_v4".to_string(), ..transaction };
+        assert!(signature.request(Address::zero()).is_err());
+    }
+
+    #[test]
+    fn it_decodes_revert_reasons() {
+        let error: Bytes = "0x08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000114e6f7420656e6f7567682066756e64732e000000000000000000000000000000"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            RevertReason::decode(&error),
+            RevertReason::Error("Not enough funds.".to_string())
+        );
+
+        let panic: Bytes =
+            "0x4e487b710000000000000000000000000000000000000000000000000000000000000011"
+                .parse()
+                .unwrap();
+        assert_eq!(RevertReason::decode(&panic), RevertReason::Panic(U256::from(0x11)));
+
+        let custom: Bytes = "0xdeadbeef".parse().unwrap();
+        assert_eq!(RevertReason::decode(&custom), RevertReason::Custom(custom));
+    }
+
+    #[cfg(feature = "testing")]
+    mod fixtures {
+        use ethers::{
+            abi::{self, Token},
+            types::{Address, Bytes, U256},
+            utils::id,
+        };
+        use frames_core::{
+            provider::{
+                chain::ChainRegistry,
+                simulation::{RevertReason, SimulationResult},
+            },
+            testing::evm::{mock_middleware, Fixtures, MockMiddleware},
+            types::transaction::{FrameTransaction, TransactionParams},
+        };
+
+        fn contract() -> Address {
+            Address::from_low_u64_be(0x42)
+        }
+
+        fn transaction(chain_id: u64, signature: &str) -> FrameTransaction {
+            FrameTransaction {
+                chain_id: format!("eip155:{}", chain_id),
+                method: "eth_sendTransaction".to_string(),
+                params: TransactionParams {
+                    abi: Vec::new(),
+                    to: contract(),
+                    value: Some("1000".to_string()),
+                    data: Some(id(signature).to_vec().into()),
+                },
+            }
+        }
+
+        fn chains() -> ChainRegistry<MockMiddleware> {
+            let revert =
+                [&id("Error(string)")[..], &abi::encode(&[Token::String("Sold out".to_string())])]
+                    .concat();
+            let fixtures = Fixtures::new(8453)
+                .with_call(contract(), id("mint()").to_vec(), Bytes::default())
+                .with_gas(contract(), id("mint()").to_vec(), 65000)
+                .with_revert(contract(), id("soldOut()").to_vec(), revert)
+                .with_revert(contract(), id("fail()").to_vec(), Bytes::default());
+
+            ChainRegistry::new().with_chain(8453, mock_middleware(fixtures))
+        }
+
+        #[tokio::test]
+        async fn it_simulates_successful_transactions() {
+            let result = chains()
+                .simulate_transaction(&transaction(8453, "mint()"), Address::from_low_u64_be(1))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                result,
+                SimulationResult::Success { gas: U256::from(65000), return_data: Bytes::default() }
+            );
+            assert_eq!(
+                serde_json::to_value(&result).unwrap(),
+                serde_json::json!({ "status": "success", "gas": "0xfde8", "returnData": "0x" })
+            );
+        }
+
+        #[tokio::test]
+        async fn it_decodes_reverted_transactions() {
+            let chains = chains();
+
+            let result = chains
+                .simulate_transaction(&transaction(8453, "soldOut()"), Address::zero())
+                .await
+                .unwrap();
+            assert_eq!(
+                result,
+                SimulationResult::Reverted { reason: RevertReason::Error("Sold out".to_string()) }
+            );
+
+            let result = chains
+                .simulate_transaction(&transaction(8453, "fail()"), Address::zero())
+                .await
+                .unwrap();
+            assert_eq!(
+                result,
+                SimulationResult::Reverted { reason: RevertReason::Custom(Bytes::default()) }
+            );
+        }
+
+        #[tokio::test]
+        async fn it_fails_on_unsupported_chains_and_rpc_errors() {
+            let chains = chains();
+
+            assert!(chains
+                .simulate_transaction(&transaction(1, "mint()"), Address::zero())
+                .await
+                .is_err());
+            assert!(chains
+                .simulate_transaction(&transaction(8453, "unknown()"), Address::zero())
+                .await
+                .is_err());
+        }
+    }
+}
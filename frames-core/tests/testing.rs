@@ -0,0 +1,71 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use frames_core::{
+        testing::signer::TestSigner,
+        types::{errors::ErrorCode, external::FrameActionPayload, message::Message},
+    };
+    use prost::Message as ProstMessage;
+
+    #[tokio::test]
+    async fn it_builds_payloads_that_verify() {
+        let signer = TestSigner::new(3);
+        let timestamp = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let payload = signer
+            .action("https://example.com/frame")
+            .button_index(2)
+            .input_text("gm")
+            .state(r#"{"step":2}"#)
+            .cast_id(1, "0x1234")
+            .timestamp(timestamp)
+            .payload();
+
+        let action = payload.verify(&signer).await.unwrap();
+        assert_eq!(action.fid, 3);
+        assert_eq!(action.url, "https://example.com/frame");
+        assert_eq!(action.button_index, 2);
+        assert_eq!(action.input_text, Some("gm".to_string()));
+        assert_eq!(action.state, Some(r#"{"step":2}"#.to_string()));
+        assert_eq!(action.cast_id.unwrap().hash, "0x1234");
+        assert_eq!(action.timestamp, timestamp);
+        assert_eq!(action.signer, format!("0x{}", hex::encode(signer.public_key())));
+        assert_eq!(payload.untrusted_data.message_hash, action.message_hash);
+    }
+
+    #[tokio::test]
+    async fn it_emits_json_payloads_and_raw_message_bytes() {
+        let signer = TestSigner::new(3);
+        let action = signer.action("https://example.com/frame").button_index(4);
+
+        let payload: FrameActionPayload = serde_json::from_str(&action.json()).unwrap();
+        assert_eq!(payload.untrusted_data.button_index, 4);
+        assert_eq!(payload.verify(&signer).await.unwrap().button_index, 4);
+
+        let message = Message::decode(action.message_bytes().as_slice()).unwrap();
+        assert_eq!(message.verify(&signer).await.unwrap().button_index, 4);
+    }
+
+    #[test]
+    fn it_signs_with_a_stable_key_per_fid() {
+        assert_eq!(TestSigner::new(3).public_key(), TestSigner::new(3).public_key());
+        assert_ne!(TestSigner::new(3).public_key(), TestSigner::new(4).public_key());
+    }
+
+    #[tokio::test]
+    async fn it_produces_messages_that_fail_verification_once_tampered() {
+        let signer = TestSigner::new(3);
+        let mut message = signer.action("https://example.com").message();
+        message.signature[0] ^= 0xff;
+
+        let errors = message.verify(&signer).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::InvalidSignature);
+    }
+
+    #[tokio::test]
+    async fn it_only_vouches_for_its_own_key() {
+        let message = TestSigner::new(3).action("https://example.com").message();
+
+        let errors = message.verify(&TestSigner::new(4)).await.err().unwrap();
+        assert_eq!(errors.errors[0].code, ErrorCode::UnregisteredSigner);
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use frames_core::{
+        types::{button::FrameButton, errors::ErrorCode, frame::Frame},
+        validators::url::url_matches,
+    };
+
+    #[test]
+    fn it_matches_urls_under_the_expected_prefix() {
+        let expected = "https://example.com/frames/";
+        assert!(url_matches("https://example.com/frames", expected));
+        assert!(url_matches("https://example.com/frames/", expected));
+        assert!(url_matches("https://EXAMPLE.com/frames/poll?page=2#top", expected));
+        assert!(url_matches("https://example.com:443/frames/poll/", expected));
+        assert!(url_matches("https://example.com/anything", "https://example.com"));
+
+        assert!(!url_matches("https://example.com/frames-evil", expected));
+        assert!(!url_matches("https://example.com/", expected));
+        assert!(!url_matches("http://example.com/frames", expected));
+        assert!(!url_matches("https://example.com.evil.com/frames", expected));
+        assert!(!url_matches("https://example.com:8443/frames", expected));
+        assert!(!url_matches("not a url", expected));
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn it_checks_the_signed_action_url() {
+        use frames_core::{testing::signer::TestSigner, types::action::FrameAction};
+
+        let signer = TestSigner::new(1);
+        let action = signer.action("https://example.com/frames/poll?id=1").payload();
+        let action = action.verify(&signer).await.unwrap();
+        assert!(action.check_url("https://example.com/frames").is_ok());
+
+        let errors = action.check_url("https://other.com/frames").unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UrlMismatch);
+        assert_eq!(errors[0].key, None);
+
+        let action = FrameAction::from(action);
+        assert!(action.check_url("https://example.com/frames/poll/").is_ok());
+        assert!(action.check_url("https://example.com/quiz").is_err());
+    }
+
+    #[test]
+    fn it_checks_the_post_url_of_the_pressed_button() {
+        let button = |id: usize, action: Option<&str>, target: Option<&str>| FrameButton {
+            id,
+            label: format!("Button {}", id),
+            action: action.map(str::to_string),
+            target: target.map(str::to_string),
+        };
+        let frame = Frame {
+            post_url: Some("https://example.com/frames/vote".to_string()),
+            buttons: vec![
+                button(1, None, None),
+                button(2, Some("post"), Some("https://example.com/frames/results")),
+                button(3, Some("link"), Some("https://docs.example.com")),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(frame.post_url_of(1), Some("https://example.com/frames/vote"));
+        assert_eq!(frame.post_url_of(2), Some("https://example.com/frames/results"));
+        assert_eq!(frame.post_url_of(3), Some("https://example.com/frames/vote"));
+
+        assert!(frame.check_post_url(1, "https://example.com/frames/vote/?page=1").is_ok());
+        assert!(frame.check_post_url(2, "https://example.com/frames/results").is_ok());
+
+        let errors = frame.check_post_url(2, "https://example.com/frames/vote").unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UrlMismatch);
+        assert_eq!(errors[0].key.as_deref(), Some("fc:frame:post_url"));
+
+        let errors = Frame::new().check_post_url(1, "https://example.com").unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::UrlMismatch);
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use ethers::{
+        abi::{self, Token},
+        providers::ens::{self, ENS_ADDRESS},
+        types::{Address, TransactionRequest},
+    };
+    use frames_core::{
+        provider::{chain::ChainRegistry, username::UsernameResolver},
+        testing::{
+            evm::{mock_middleware, Fixtures, MockMiddleware},
+            http::HttpStub,
+        },
+    };
+
+    const CUSTODY: &str = "0x8773442740C17C9d0F0B87022c722F9a136206eD";
+
+    fn transfer(username: &str, fid: u64) -> String {
+        format!(
+            r#"{{"transfer":{{"id":1,"timestamp":1628882891,"username":"{}","owner":"{}","from":0,"to":{},"user_signature":"0x","server_signature":"0x"}}}}"#,
+            username, CUSTODY, fid
+        )
+    }
+
+    fn with_request(fixtures: Fixtures, request: TransactionRequest, result: Vec<u8>) -> Fixtures {
+        let to = *request.to.unwrap().as_address().unwrap();
+        fixtures.with_call(to, request.data.unwrap(), result)
+    }
+
+    fn chains() -> ChainRegistry<MockMiddleware> {
+        let custody: Address = CUSTODY.parse().unwrap();
+        let resolver = Address::from_low_u64_be(0xe5);
+        let reverse_name = ens::reverse_address(custody);
+
+        let mut mainnet = Fixtures::new(1);
+        for (request, result) in [
+            (ens::get_resolver(ENS_ADDRESS, "farcaster.eth"), Token::Address(resolver)),
+            (ens::get_resolver(ENS_ADDRESS, "nobody.eth"), Token::Address(Address::zero())),
+            (ens::get_resolver(ENS_ADDRESS, &reverse_name), Token::Address(resolver)),
+            (ens::supports_interface(resolver, ens::ADDR_SELECTOR), Token::Bool(true)),
+            (
+                ens::resolve(resolver, ens::ADDR_SELECTOR, "farcaster.eth", None),
+                Token::Address(custody),
+            ),
+            (
+                ens::resolve(resolver, ens::NAME_SELECTOR, &reverse_name, None),
+                Token::String("farcaster.eth".to_string()),
+            ),
+        ] {
+            mainnet = with_request(mainnet, request, abi::encode(&[result]));
+        }
+        let optimism =
+            Fixtures::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/optimism.json"))
+                .unwrap();
+
+        ChainRegistry::new()
+            .with_chain(1, mock_middleware(mainnet))
+            .with_chain(10, mock_middleware(optimism))
+    }
+
+    #[tokio::test]
+    async fn it_resolves_fnames_through_the_fname_server() {
+        let server = HttpStub::start().await;
+        server
+            .route("/transfers/current?name=dwr", 200, &transfer("dwr", 3))
+            .route("/transfers/current?fid=3", 200, &transfer("dwr", 3))
+            .route("/transfers/current?name=gone", 200, &transfer("gone", 0));
+        let resolver = UsernameResolver::new(&server.url, ChainRegistry::<MockMiddleware>::new());
+
+        assert_eq!(resolver.fid_of("@DWR").await.unwrap(), Some(3));
+        assert_eq!(resolver.username_of(3).await.unwrap(), Some("dwr".to_string()));
+        assert_eq!(resolver.fid_of("nobody").await.unwrap(), None);
+        assert_eq!(resolver.fid_of("gone").await.unwrap(), None);
+        assert_eq!(resolver.fid_of("vitalik.eth").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn it_caches_resolved_usernames() {
+        let server = HttpStub::start().await;
+        server.route("/transfers/current?name=dwr", 200, &transfer("dwr", 3));
+        let resolver = UsernameResolver::new(&server.url, ChainRegistry::<MockMiddleware>::new());
+
+        assert_eq!(resolver.fid_of("dwr").await.unwrap(), Some(3));
+        assert_eq!(resolver.fid_of("dwr").await.unwrap(), Some(3));
+        assert_eq!(resolver.fid_of("nobody").await.unwrap(), None);
+        assert_eq!(resolver.fid_of("nobody").await.unwrap(), None);
+        assert_eq!(server.requests().len(), 2);
+
+        resolver.clear_cache();
+        assert_eq!(resolver.fid_of("dwr").await.unwrap(), Some(3));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn it_resolves_ens_names_on_chain() {
+        let server = HttpStub::start().await;
+        let resolver = UsernameResolver::new(&server.url, chains());
+
+        assert_eq!(resolver.fid_of("farcaster.eth").await.unwrap(), Some(1));
+        assert_eq!(resolver.fid_of("nobody.eth").await.unwrap(), None);
+        assert_eq!(resolver.username_of(1).await.unwrap(), Some("farcaster.eth".to_string()));
+        assert_eq!(server.requests(), vec!["/transfers/current?fid=1"]);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(all(test, feature = "xmtp", feature = "testing"))]
+mod tests {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use chrono::{TimeZone, Utc};
+    use frames_core::{
+        testing::xmtp::XmtpTestSigner,
+        types::{
+            action::Actor,
+            errors::ErrorCode,
+            xmtp::{SignatureUnion, XmtpFrameActionPayload},
+        },
+    };
+    use prost::Message;
+
+    #[test]
+    fn it_verifies_xmtp_frame_actions() {
+        let signer = XmtpTestSigner::new(1);
+        let timestamp = Utc.timestamp_millis_opt(1_710_000_000_123).unwrap();
+        let json = signer
+            .action("https://example.com/frame")
+            .button_index(2)
+            .input_text("gm")
+            .state("{\"step\":1}")
+            .conversation("0xconversation")
+            .timestamp(timestamp)
+            .json();
+
+        let payload: XmtpFrameActionPayload = serde_json::from_str(&json).unwrap();
+        let action = payload.verify().unwrap();
+
+        assert_eq!(
+            action.actor,
+            Actor::Xmtp {
+                wallet_address: format!("{:?}", signer.wallet_address()),
+                opaque_conversation_identifier: "0xconversation".to_string(),
+            }
+        );
+        assert_eq!(action.signer, format!("{:?}", signer.identity_address()));
+        assert_eq!(action.url, "https://example.com/frame");
+        assert_eq!(action.button_index, 2);
+        assert_eq!(action.input_text.as_deref(), Some("gm"));
+        assert_eq!(action.state.as_deref(), Some("{\"step\":1}"));
+        assert_eq!(action.transaction_id, None);
+        assert_eq!(action.timestamp, timestamp);
+        assert!(action.message_hash.starts_with("0x") && action.message_hash.len() == 66);
+    }
+
+    #[test]
+    fn it_rejects_identity_keys_of_other_wallets() {
+        let signer = XmtpTestSigner::new(1);
+        let impostor = XmtpTestSigner::new(2);
+
+        let payload =
+            signer.action("https://example.com").identity_key(impostor.identity_key()).payload();
+        let errors = payload.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+    }
+
+    #[test]
+    fn it_rejects_tampered_action_bodies() {
+        let signer = XmtpTestSigner::new(1);
+        let mut action = signer.action("https://example.com").action();
+        action.action_body = signer.action("https://evil.com").action().action_body;
+
+        let errors = action.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+
+        let mut action = signer.action("https://example.com").action();
+        let identity_key = action
+            .signed_public_key_bundle
+            .as_mut()
+            .and_then(|bundle| bundle.identity_key.as_mut())
+            .unwrap();
+        if let Some(SignatureUnion::WalletEcdsaCompact(signature)) =
+            identity_key.signature.as_mut().and_then(|signature| signature.union.as_mut())
+        {
+            signature.bytes = vec![0; 64];
+        }
+        let errors = action.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidSignature);
+    }
+
+    #[test]
+    fn it_reports_malformed_payloads() {
+        let signer = XmtpTestSigner::new(1);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.client_protocol = "farcaster@vNext".to_string();
+        let errors = payload.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidActionPayload);
+
+        let mut payload = signer.action("https://example.com").payload();
+        payload.trusted_data.message_bytes = "not base64!".to_string();
+        let errors = payload.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidMessageBytes);
+
+        let mut action = signer.action("https://example.com").action();
+        action.signed_public_key_bundle = None;
+        payload.trusted_data.message_bytes = STANDARD.encode(action.encode_to_vec());
+        let errors = payload.verify().unwrap_err().errors;
+        assert_eq!(errors[0].code, ErrorCode::InvalidMessageBytes);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use actix_web::{http::header, web, HttpResponse};
+use frames_core::{
+    testing::signer::TestSigner,
+    types::{
+        errors::ErrorCode,
+        frame::{Frame, ValidationOptions},
+        message::FarcasterNetwork,
+    },
+};
+use serde::Deserialize;
+
+pub mod views;
+
+pub struct Debugger {
+    pub signer: TestSigner,
+    client: reqwest::Client,
+}
+
+impl Debugger {
+    pub fn new(fid: u64) -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("Build HTTP Client Error");
+        Debugger { signer: TestSigner::new(fid), client }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/", web::get().to(index))
+        .route("/frame", web::get().to(load_frame))
+        .route("/action", web::post().to(post_action));
+}
+
+#[derive(Deserialize)]
+struct FrameQuery {
+    url: String,
+}
+
+async fn index() -> HttpResponse {
+    html(views::index())
+}
+
+async fn load_frame(query: web::Query<FrameQuery>) -> HttpResponse {
+    let url = query.into_inner().url;
+    let frame_url = url.clone();
+    let result = web::block(move || {
+        let mut frame = Frame::new();
+        let errors = frame.from_url_with(&url, &ValidationOptions::local()).err();
+        (frame, errors)
+    })
+    .await;
+
+    match result {
+        Ok((_, Some(errors)))
+            if errors.errors.iter().any(|error| {
+                error.code == ErrorCode::FailedToFetchFrameHTML ||
+                    error.code == ErrorCode::FailedToReadResponse
+            }) =>
+        {
+            html(views::notice(&frame_url, &errors.to_string()))
+        }
+        Ok((frame, errors)) => html(views::frame(&frame_url, &frame, errors.as_ref())),
+        Err(e) => html(views::notice(&frame_url, &e.to_string())),
+    }
+}
+
+async fn post_action(
+    debugger: web::Data<Debugger>,
+    form: web::Form<HashMap<String, String>>,
+) -> HttpResponse {
+    let form = form.into_inner();
+    let frame_url = form.get("frame_url").cloned().unwrap_or_default();
+    let button = match form.get("button").and_then(|button| button.parse::<u32>().ok()) {
+        Some(button) => button,
+        None => return html(views::notice(&frame_url, "No button was pressed.")),
+    };
+    let post_url = form
+        .get(&format!("target_{}", button))
+        .or_else(|| form.get("post_url"))
+        .cloned()
+        .unwrap_or_else(|| frame_url.clone());
+    let mut action =
+        debugger.signer.action(&frame_url).button_index(button).network(FarcasterNetwork::Devnet);
+    if let Some(input_text) = form.get("input_text").filter(|text| !text.is_empty()) {
+        action = action.input_text(input_text);
+    }
+
+    let payload = action.payload();
+    let response = match debugger.client.post(&post_url).json(&payload).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            return html(views::notice(&frame_url, &format!("POST {} failed: {}", post_url, e)))
+        }
+    };
+
+    let status = response.status();
+    if status.is_redirection() {
+        let location = response
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        return html(views::notice(&frame_url, &format!("Redirected to {}", location)));
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return html(views::notice(
+            &frame_url,
+            &format!("POST {} responded with {}: {}", post_url, status, body),
+        ));
+    }
+
+    let mut frame = Frame::new();
+    let errors = frame.from_html_with(&body, &ValidationOptions::local()).err();
+    html(views::frame(&frame_url, &frame, errors.as_ref()))
+}
+
+fn html(body: String) -> HttpResponse {
+    HttpResponse::Ok().content_type(header::ContentType::html()).body(body)
+}
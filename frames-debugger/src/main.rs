@@ -0,0 +1,30 @@
+use actix_web::{web, App, HttpServer};
+use frames_debugger::{configure, Debugger};
+
+const DEFAULT_PORT: u16 = 8450;
+const DEFAULT_FID: u64 = 1;
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let mut port = DEFAULT_PORT;
+    let mut fid = DEFAULT_FID;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--port", Some(value)) => port = value.parse().expect("Invalid --port"),
+            ("--fid", Some(value)) => fid = value.parse().expect("Invalid --fid"),
+            _ => {
+                eprintln!("usage: frames-debugger [--port <port>] [--fid <fid>]");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let debugger = web::Data::new(Debugger::new(fid));
+    println!("frames debugger listening on http://127.0.0.1:{}", port);
+    HttpServer::new(move || App::new().app_data(debugger.clone()).configure(configure))
+        .bind(("127.0.0.1", port))?
+        .run()
+        .await
+}
@@ -0,0 +1,117 @@
+use frames_core::types::{errors::FrameErrors, frame::Frame};
+
+const STYLE: &str = "body{font-family:sans-serif;max-width:720px;margin:2rem auto;padding:0 1rem}\
+img{width:100%;border:1px solid #ddd;border-radius:8px}\
+form.url{display:flex;gap:.5rem}form.url input{flex:1}\
+.buttons{display:flex;gap:.5rem;margin:.5rem 0}.buttons>*{flex:1;text-align:center}\
+.errors{color:#b00020}.notice{background:#f4f4f4;padding:.5rem}";
+
+pub fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+pub fn layout(frame_url: &str, content: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><title>frames debugger</title><style>{}</style></head><body>\
+         <h1>frames debugger</h1>\
+         <form class=\"url\" method=\"get\" action=\"/frame\">\
+         <input name=\"url\" placeholder=\"http://localhost:3000\" value=\"{}\" />\
+         <button type=\"submit\">Load</button></form>{}</body></html>",
+        STYLE,
+        escape(frame_url),
+        content
+    )
+}
+
+pub fn index() -> String {
+    layout("", "")
+}
+
+pub fn notice(frame_url: &str, message: &str) -> String {
+    layout(frame_url, &format!("<p class=\"notice\">{}</p>", escape(message)))
+}
+
+pub fn frame(frame_url: &str, frame: &Frame, errors: Option<&FrameErrors>) -> String {
+    let mut html = format!(
+        "<h2>{}</h2><img src=\"{}\" alt=\"frame image\" data-aspect-ratio=\"{}\" />",
+        escape(&frame.title),
+        escape(&frame.image.url),
+        frame.image.aspect_ratio
+    );
+
+    html += "<form method=\"post\" action=\"/action\">";
+    html +=
+        &format!("<input type=\"hidden\" name=\"frame_url\" value=\"{}\" />", escape(frame_url));
+    if let Some(post_url) = &frame.post_url {
+        html +=
+            &format!("<input type=\"hidden\" name=\"post_url\" value=\"{}\" />", escape(post_url));
+    }
+    if let Some(input_text) = &frame.input_text {
+        html += &format!(
+            "<input name=\"input_text\" placeholder=\"{}\" maxlength=\"256\" />",
+            escape(input_text)
+        );
+    }
+
+    let mut buttons = frame.buttons.iter().collect::<Vec<_>>();
+    buttons.sort_by_key(|button| button.id);
+    html += "<div class=\"buttons\">";
+    for button in buttons {
+        match (button.action.as_deref(), &button.target) {
+            (Some("link"), Some(target)) => {
+                html += &format!(
+                    "<a href=\"{}\" target=\"_blank\">{} ↗</a>",
+                    escape(target),
+                    escape(&button.label)
+                )
+            }
+            (action, target) => {
+                if let Some(target) = target {
+                    html += &format!(
+                        "<input type=\"hidden\" name=\"target_{}\" value=\"{}\" />",
+                        button.id,
+                        escape(target)
+                    );
+                }
+                html += &format!(
+                    "<button type=\"submit\" name=\"button\" value=\"{}\" title=\"{}\">{}</button>",
+                    button.id,
+                    escape(&format!(
+                        "{}{}",
+                        action.unwrap_or("post"),
+                        target.as_ref().map(|t| format!(" → {}", t)).unwrap_or_default()
+                    )),
+                    escape(&button.label)
+                )
+            }
+        }
+    }
+    html += "</div></form>";
+
+    html += &report(errors);
+    layout(frame_url, &html)
+}
+
+fn report(errors: Option<&FrameErrors>) -> String {
+    match errors {
+        None => "<h3>Validation</h3><p>No errors found.</p>".to_string(),
+        Some(errors) => {
+            let mut html = "<h3>Validation</h3><ul class=\"errors\">".to_string();
+            for error in &errors.errors {
+                html += &format!(
+                    "<li><code>{:?}</code> {} <code>{}</code></li>",
+                    error.code,
+                    escape(&error.description),
+                    escape(error.key.as_deref().unwrap_or_default())
+                );
+            }
+            html += "</ul>";
+            html
+        }
+    }
+}
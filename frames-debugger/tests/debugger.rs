@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use actix_web::{test, web, App, HttpResponse, HttpServer};
+    use frames_core::{testing::signer::TestSigner, types::external::FrameActionPayload};
+    use frames_debugger::{configure, Debugger};
+
+    fn frame_html(port: u16, image: &str) -> String {
+        format!(
+            r#"<html><head><title>Local</title>
+            <meta name="fc:frame" content="vNext" />
+            <meta name="fc:frame:image" content="http://localhost:{port}/{image}.png" />
+            <meta name="fc:frame:post_url" content="http://localhost:{port}/next" />
+            <meta name="fc:frame:input:text" content="Your name" />
+            <meta name="fc:frame:button:1" content="Next" />
+            <meta name="fc:frame:button:2" content="Docs" />
+            <meta name="fc:frame:button:2:action" content="link" />
+            <meta name="fc:frame:button:2:target" content="https://docs.farcaster.xyz" />
+            </head></html>"#
+        )
+    }
+
+    fn spawn_frame_server() -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = HttpServer::new(move || {
+            App::new()
+                .route(
+                    "/",
+                    web::get().to(move || async move {
+                        HttpResponse::Ok().body(frame_html(port, "start"))
+                    }),
+                )
+                .route(
+                    "/next",
+                    web::post().to(move |payload: web::Json<FrameActionPayload>| async move {
+                        // the debugger signs with the test key of its fid
+                        match payload.verify(&TestSigner::new(42)).await {
+                            Ok(action) => HttpResponse::Ok().body(frame_html(
+                                port,
+                                &format!(
+                                    "fid-{}-button-{}-{}",
+                                    action.fid,
+                                    action.button_index,
+                                    action.input_text.unwrap_or_default()
+                                ),
+                            )),
+                            Err(errors) => HttpResponse::BadRequest().body(errors.to_string()),
+                        }
+                    }),
+                )
+        })
+        .listen(listener)
+        .unwrap()
+        .workers(1)
+        .run();
+        actix_web::rt::spawn(server);
+        port
+    }
+
+    #[actix_web::test]
+    async fn it_loads_a_localhost_frame_and_posts_signed_actions() {
+        let port = spawn_frame_server();
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(Debugger::new(42))).configure(configure),
+        )
+        .await;
+
+        let frame_url = format!("http://localhost:{}/", port);
+        let req = test::TestRequest::get().uri(&format!("/frame?url={}", frame_url)).to_request();
+        let html = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+        assert!(html.contains(&format!("http://localhost:{}/start.png", port)));
+        assert!(html.contains("No errors found."));
+        assert!(html.contains("href=\"https://docs.farcaster.xyz\""));
+
+        let req = test::TestRequest::post()
+            .uri("/action")
+            .set_form([
+                ("frame_url", frame_url.as_str()),
+                ("post_url", &format!("http://localhost:{}/next", port)),
+                ("input_text", "alice"),
+                ("button", "1"),
+            ])
+            .to_request();
+        let html = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+        assert!(html.contains(&format!("http://localhost:{}/fid-42-button-1-alice.png", port)));
+    }
+
+    #[actix_web::test]
+    async fn it_reports_unreachable_frames() {
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(Debugger::new(42))).configure(configure),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/frame?url=http://localhost:1/").to_request();
+        let html = String::from_utf8(test::call_and_read_body(&app, req).await.to_vec()).unwrap();
+        assert!(html.contains("Failed to fetch frame HTML."));
+    }
+}